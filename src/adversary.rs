@@ -0,0 +1,116 @@
+use crate::grade::{grade_guess, Grader};
+use crate::hint::{HintType, WordleHint};
+use std::collections::HashMap;
+
+/// Absurdle-style grader that never commits to an answer. Each guess is answered with
+/// the hint pattern that keeps the most answers consistent with everything revealed so far
+pub struct AdversarialGrader {
+    candidates: Vec<String>,
+    num_guesses: usize,
+    forced_after: Option<usize>,
+}
+
+impl AdversarialGrader {
+    /// Start a game over these answers, or None if there are none to play against
+    pub fn new(answers: &[&str]) -> Option<Self> {
+        if answers.is_empty() {
+            return None;
+        }
+        let candidates: Vec<String> = answers.iter().map(|w| w.to_string()).collect();
+        let forced_after = match candidates.len() {
+            1 => Some(0),
+            _ => None,
+        };
+        Some(AdversarialGrader {
+            candidates,
+            num_guesses: 0,
+            forced_after,
+        })
+    }
+
+    /// Answers still consistent with every hint given so far
+    pub fn remaining(&self) -> &[String] {
+        &self.candidates
+    }
+
+    /// Number of guesses after which only one answer remained, if that has happened yet
+    pub fn forced_after(&self) -> Option<usize> {
+        self.forced_after
+    }
+}
+
+impl Grader for AdversarialGrader {
+    fn grade(&mut self, guess: &str) -> WordleHint {
+        self.num_guesses += 1;
+
+        // Bucket candidates by the hint they would give, remembering first-seen order so
+        // ties are broken deterministically
+        let mut buckets: HashMap<Vec<HintType>, Vec<String>> = HashMap::new();
+        let mut order: Vec<Vec<HintType>> = vec![];
+        for candidate in self.candidates.drain(..) {
+            let pattern = grade_guess(guess, &candidate).hint_types().to_vec();
+            if !buckets.contains_key(&pattern) {
+                order.push(pattern.clone());
+            }
+            buckets.entry(pattern).or_default().push(candidate);
+        }
+
+        // Largest bucket wins; on ties prefer the pattern revealing the fewest greens, then
+        // the fewest yellows, as Absurdle does. new() refuses an empty answer set and the
+        // kept bucket is never empty, so there is always a pattern to pick
+        let count = |pattern: &[HintType], hint_type: HintType| {
+            pattern.iter().filter(|h| **h == hint_type).count()
+        };
+        let best = order
+            .into_iter()
+            .enumerate()
+            .max_by_key(|(i, pattern)| {
+                (
+                    buckets[pattern].len(),
+                    std::cmp::Reverse(count(pattern, HintType::Correct)),
+                    std::cmp::Reverse(count(pattern, HintType::Present)),
+                    std::cmp::Reverse(*i),
+                )
+            })
+            .map(|(_, pattern)| pattern)
+            .unwrap();
+
+        self.candidates = buckets.remove(&best).unwrap();
+        if self.candidates.len() == 1 && self.forced_after.is_none() {
+            self.forced_after = Some(self.num_guesses);
+        }
+        WordleHint::new(guess.to_string(), best)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keeps_largest_bucket() {
+        let mut grader = AdversarialGrader::new(&["cigar", "civic", "robot", "rotor"]).unwrap();
+        // "crane" splits these as {cigar}, {civic}, {robot, rotor}
+        let hint = grader.grade("crane");
+        assert_eq!(hint.hints(), "X~XXX");
+        assert_eq!(grader.remaining(), ["robot", "rotor"]);
+        assert_eq!(grader.forced_after(), None);
+    }
+
+    #[test]
+    fn test_avoids_solving_on_ties() {
+        let mut grader = AdversarialGrader::new(&["robot", "rebus"]).unwrap();
+        let hint = grader.grade("robot");
+        assert!(!hint.is_fully_correct());
+        assert_eq!(grader.remaining(), ["rebus"]);
+        assert_eq!(grader.forced_after(), Some(1));
+
+        assert!(grader.grade("rebus").is_fully_correct());
+        assert_eq!(grader.forced_after(), Some(1));
+    }
+
+    #[test]
+    fn test_no_answers_no_game() {
+        assert!(AdversarialGrader::new(&[]).is_none());
+    }
+}
@@ -2,8 +2,9 @@ use pyo3::PyErr;
 use reqwest::blocking::Client;
 use serde::{Deserialize, Serialize};
 
-use crate::common::API_GUESSES_ENDPOINT;
-use crate::hint::WordleHint;
+use crate::common::{API_GUESSES_ENDPOINT, DUMMY_GUESS};
+use crate::hint::{HintType, WordleHint};
+use crate::normalize::word_length;
 
 #[derive(Serialize)]
 struct GuessRequest {
@@ -58,15 +59,29 @@ pub fn submit_guesses(team_id: &str, guesses: &[String]) -> Result<Vec<WordleHin
         .iter()
         .zip(guess_response.hints.iter())
         .map(|(word, hint_str)| {
-            if hint_str == "OOOOO" {
+            if is_all_correct(word, hint_str) {
                 Ok(WordleHint::new_all_correct(word.clone()))
             } else {
-                WordleHint::new_hint(word.clone(), hint_str.clone())
+                WordleHint::new_hint(word.clone(), hint_str.clone()).map_err(|e| {
+                    PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+                        "Server returned a malformed hint '{}' for guess {}: {}",
+                        hint_str, word, e
+                    ))
+                })
             }
         })
         .collect::<Result<Vec<WordleHint>, PyErr>>()
 }
 
+/// Whether the server's hint marks the guess fully correct. Already-solved targets get an
+/// all-correct hint for the dummy guess; any other all-correct hint must cover every letter
+/// of the guess, so a short or empty one is reported as malformed instead
+fn is_all_correct(word: &str, hint_str: &str) -> bool {
+    let all_correct =
+        !hint_str.is_empty() && hint_str.chars().all(|c| c == HintType::Correct.to_char());
+    all_correct && (word == DUMMY_GUESS || word_length(hint_str) == word_length(word))
+}
+
 /// Send start signal to server to start tournament evaluation
 pub fn send_start_signal(team_id: &str) -> Result<(), PyErr> {
     println!("Sending mock start signal to server for team {}", team_id);
@@ -81,3 +96,18 @@ pub fn send_end_signal(team_id: &str) -> Result<f64, PyErr> {
     // Should return the weighted server score
     Ok(0.0)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_all_correct_needs_full_length() {
+        assert!(is_all_correct("crane", "OOOOO"));
+        assert!(is_all_correct(DUMMY_GUESS, "OOOOO"));
+        assert!(!is_all_correct("crane", "OOO"));
+        assert!(!is_all_correct("crane", ""));
+        assert!(!is_all_correct(DUMMY_GUESS, ""));
+        assert!(!is_all_correct("crane", "OOOO~"));
+    }
+}
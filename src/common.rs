@@ -1,6 +1,9 @@
 pub const NUM_TARGET_WORDS: usize = 1000;
 pub const WORD_LENGTH: usize = 5;
+pub const MIN_WORD_LENGTH: usize = 2;
+pub const MAX_WORD_LENGTH: usize = 12;
 pub const MAX_GUESSES: usize = 20;
+pub const MAX_BOARDS: usize = 32;
 pub const DUMMY_GUESS: &str = "imagine guessing more than 5 letters";
 
 // Eventually this will be injected at compile time
@@ -0,0 +1,128 @@
+use crate::grade::grade_guess;
+use crate::hint::WordleHint;
+use crate::normalize::word_length;
+use pyo3::prelude::*;
+
+/// Whether a word produces exactly this hint when it is the answer
+pub fn fits_hint(answer: &str, hint: &WordleHint) -> bool {
+    word_length(answer) == hint.word_length()
+        && grade_guess(hint.word(), answer).hint_types() == hint.hint_types()
+}
+
+/// Drop every candidate answer that would not have produced `hint`
+pub fn retain_consistent(candidates: &mut Vec<&str>, hint: &WordleHint) {
+    candidates.retain(|answer| fits_hint(answer, hint));
+}
+
+/// Result of checking a hint history against a word list. The history is consistent if some
+/// word in the list produces exactly those hints; otherwise first_inconsistent_hint is the
+/// index of the first hint after which no word in the list fits
+#[pyclass(frozen)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HistoryCheck {
+    /// A word in the list that fits every hint, if there is one
+    #[pyo3(get)]
+    witness: Option<String>,
+    /// Number of words in the list that fit every hint
+    #[pyo3(get)]
+    num_consistent: usize,
+    #[pyo3(get)]
+    first_inconsistent_hint: Option<usize>,
+}
+
+#[pymethods]
+impl HistoryCheck {
+    #[getter]
+    pub fn consistent(&self) -> bool {
+        self.first_inconsistent_hint.is_none()
+    }
+
+    fn __bool__(&self) -> bool {
+        self.consistent()
+    }
+
+    fn __repr__(&self) -> String {
+        match self.first_inconsistent_hint {
+            None => format!(
+                "HistoryCheck(consistent=True, witness={}, num_consistent={})",
+                match &self.witness {
+                    Some(word) => format!("'{}'", word),
+                    None => "None".to_string(),
+                },
+                self.num_consistent
+            ),
+            Some(index) => format!(
+                "HistoryCheck(consistent=False, first_inconsistent_hint={})",
+                index
+            ),
+        }
+    }
+}
+
+impl HistoryCheck {
+    pub fn witness(&self) -> Option<&str> {
+        self.witness.as_deref()
+    }
+
+    pub fn first_inconsistent_hint(&self) -> Option<usize> {
+        self.first_inconsistent_hint
+    }
+}
+
+/// Check whether any word in `words` produces exactly the hints in `history`, filtering hint by
+/// hint so the first hint that rules out every remaining word can be named
+pub fn check_history(history: &[WordleHint], words: &[&str]) -> HistoryCheck {
+    let mut candidates = words.to_vec();
+    for (i, hint) in history.iter().enumerate() {
+        retain_consistent(&mut candidates, hint);
+        if candidates.is_empty() {
+            return HistoryCheck {
+                witness: None,
+                num_consistent: 0,
+                first_inconsistent_hint: Some(i),
+            };
+        }
+    }
+    HistoryCheck {
+        witness: candidates.first().map(|word| word.to_string()),
+        num_consistent: candidates.len(),
+        first_inconsistent_hint: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WORDS: [&str; 4] = ["cigar", "civic", "robot", "rotor"];
+
+    #[test]
+    fn test_consistent_history_has_witness() {
+        let history = [grade_guess("crane", "rotor"), grade_guess("robot", "rotor")];
+        let check = check_history(&history, &WORDS);
+        assert!(check.consistent());
+        assert_eq!(check.witness(), Some("rotor"));
+        assert_eq!(check.num_consistent, 1);
+    }
+
+    #[test]
+    fn test_names_first_impossible_hint() {
+        // Each hint is possible on its own, but no word gives both
+        let history = [
+            grade_guess("crane", "cigar"),
+            grade_guess("robot", "rotor"),
+            grade_guess("cigar", "cigar"),
+        ];
+        let check = check_history(&history, &WORDS);
+        assert!(!check.consistent());
+        assert_eq!(check.first_inconsistent_hint(), Some(1));
+        assert_eq!(check.witness(), None);
+    }
+
+    #[test]
+    fn test_empty_history_and_other_lengths() {
+        assert_eq!(check_history(&[], &WORDS).num_consistent, 4);
+        let check = check_history(&[grade_guess("abc", "abc")], &WORDS);
+        assert_eq!(check.first_inconsistent_hint(), Some(0));
+    }
+}
@@ -0,0 +1,384 @@
+use crate::hint::{HintType, WordleHint};
+use crate::normalize::{normalize_word, word_length};
+use pyo3::prelude::*;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+/// A hint whose length differs from the hints folded in before it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HintLengthMismatch {
+    pub word: String,
+    pub found: usize,
+    pub expected: usize,
+}
+
+impl fmt::Display for HintLengthMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Hint for {} has {} letters, expected {}",
+            self.word, self.found, self.expected
+        )
+    }
+}
+
+impl From<HintLengthMismatch> for PyErr {
+    fn from(err: HintLengthMismatch) -> PyErr {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(err.to_string())
+    }
+}
+
+/// Everything a hint list reveals about the answer: fixed greens, positions each letter is
+/// known not to occupy, and the minimum and maximum count of each letter. A gray for a letter
+/// that is also green or yellow in the same guess caps that letter's count, so duplicate
+/// letters are handled exactly as grade_guess grades them
+#[pyclass]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Constraints {
+    greens: Vec<Option<char>>,
+    excluded: BTreeMap<char, BTreeSet<usize>>,
+    min_counts: BTreeMap<char, usize>,
+    max_counts: BTreeMap<char, usize>,
+}
+
+#[pymethods]
+impl Constraints {
+    #[new]
+    #[pyo3(signature = (hints = vec![]))]
+    fn new_constraints(hints: Vec<WordleHint>) -> PyResult<Self> {
+        Ok(Constraints::from_hints(&hints)?)
+    }
+
+    /// Fold one more hint into the constraints
+    #[pyo3(name = "add_hint")]
+    fn add_hint_py(&mut self, hint: &WordleHint) -> PyResult<()> {
+        Ok(self.add_hint(hint)?)
+    }
+
+    /// Fold another set of constraints into this one
+    #[pyo3(name = "merge")]
+    fn merge_py(&mut self, other: &Constraints) -> PyResult<()> {
+        if let Some(length) = other.word_length() {
+            self.check_length(length)?;
+        }
+        self.merge(other);
+        Ok(())
+    }
+
+    /// Whether a word could still be the answer
+    #[pyo3(name = "allows")]
+    fn allows_py(&self, word: &str) -> bool {
+        self.allows(&normalize_word(word, false, ""))
+    }
+
+    #[getter(word_length)]
+    fn word_length_py(&self) -> Option<usize> {
+        self.word_length()
+    }
+
+    /// Known letter at each position, None where it is still open
+    #[getter]
+    fn greens(&self) -> Vec<Option<char>> {
+        self.greens.clone()
+    }
+
+    /// 0-based positions each letter is known not to occupy
+    #[getter]
+    pub fn excluded_positions(&self) -> BTreeMap<char, Vec<usize>> {
+        self.excluded
+            .iter()
+            .map(|(letter, positions)| (*letter, positions.iter().copied().collect()))
+            .collect()
+    }
+
+    /// Minimum number of times each letter appears in the answer
+    #[getter]
+    pub fn min_counts(&self) -> BTreeMap<char, usize> {
+        self.min_counts
+            .iter()
+            .filter(|(_, min)| **min > 0)
+            .map(|(letter, min)| (*letter, *min))
+            .collect()
+    }
+
+    /// Maximum number of times each letter appears in the answer, for letters that have
+    /// been capped by a gray (0 for letters known to be absent)
+    #[getter]
+    pub fn max_counts(&self) -> BTreeMap<char, usize> {
+        self.max_counts.clone()
+    }
+
+    fn __repr__(&self) -> String {
+        let greens: String = self.greens.iter().map(|g| g.unwrap_or('.')).collect();
+        let counts: Vec<String> = self
+            .min_counts
+            .iter()
+            .filter(|(_, min)| **min > 0)
+            .map(|(letter, min)| match self.max_counts.get(letter) {
+                Some(max) if max == min => format!("{}={}", letter, min),
+                Some(max) => format!("{}:{}-{}", letter, min, max),
+                None => format!("{}>={}", letter, min),
+            })
+            .collect();
+        let absent: String = self
+            .max_counts
+            .iter()
+            .filter(|(_, max)| **max == 0)
+            .map(|(letter, _)| *letter)
+            .collect();
+        format!(
+            "Constraints(greens='{}', counts=[{}], absent='{}')",
+            greens,
+            counts.join(", "),
+            absent
+        )
+    }
+}
+
+impl Constraints {
+    /// Fold every hint into fresh constraints. Fails if the hints have different lengths
+    pub fn from_hints(hints: &[WordleHint]) -> Result<Self, HintLengthMismatch> {
+        let mut constraints = Constraints::default();
+        for hint in hints {
+            constraints.add_hint(hint)?;
+        }
+        Ok(constraints)
+    }
+
+    /// Word length the constraints apply to, None until a hint has been added
+    pub fn word_length(&self) -> Option<usize> {
+        match self.greens.is_empty() {
+            true => None,
+            false => Some(self.greens.len()),
+        }
+    }
+
+    /// Fold one more hint into the constraints. Fails, leaving them unchanged, if its length
+    /// differs from earlier hints
+    pub fn add_hint(&mut self, hint: &WordleHint) -> Result<(), HintLengthMismatch> {
+        if let Some(expected) = self.word_length() {
+            if expected != hint.word_length() {
+                return Err(HintLengthMismatch {
+                    word: hint.word().to_string(),
+                    found: hint.word_length(),
+                    expected,
+                });
+            }
+        }
+        if self.greens.is_empty() {
+            self.greens = vec![None; hint.word_length()];
+        }
+
+        let mut shown: BTreeMap<char, usize> = BTreeMap::new();
+        let mut capped: BTreeSet<char> = BTreeSet::new();
+        for (position, (letter, hint_type)) in
+            hint.word().chars().zip(hint.hint_types()).enumerate()
+        {
+            match hint_type {
+                HintType::Correct => self.greens[position] = Some(letter),
+                HintType::Present | HintType::Absent => {
+                    self.excluded.entry(letter).or_default().insert(position);
+                }
+            }
+            match hint_type {
+                HintType::Correct | HintType::Present => *shown.entry(letter).or_default() += 1,
+                HintType::Absent => {
+                    shown.entry(letter).or_default();
+                    capped.insert(letter);
+                }
+            }
+        }
+
+        // Greens and yellows of a letter give a lower bound on its count; a gray of the same
+        // letter in the same guess means there are no more than that
+        for (letter, count) in shown {
+            let min = self.min_counts.entry(letter).or_default();
+            *min = (*min).max(count);
+            if capped.contains(&letter) {
+                let max = self.max_counts.entry(letter).or_insert(count);
+                *max = (*max).min(count);
+            }
+        }
+        Ok(())
+    }
+
+    /// Fold another set of constraints into this one. Panics if their word lengths differ
+    pub fn merge(&mut self, other: &Constraints) {
+        if self.greens.is_empty() {
+            self.greens = vec![None; other.greens.len()];
+        }
+        if !other.greens.is_empty() {
+            assert_eq!(
+                self.greens.len(),
+                other.greens.len(),
+                "Constraints are for different word lengths"
+            );
+        }
+
+        for (green, other_green) in self.greens.iter_mut().zip(&other.greens) {
+            if other_green.is_some() {
+                *green = *other_green;
+            }
+        }
+        for (letter, positions) in &other.excluded {
+            self.excluded
+                .entry(*letter)
+                .or_default()
+                .extend(positions.iter().copied());
+        }
+        for (letter, count) in &other.min_counts {
+            let min = self.min_counts.entry(*letter).or_default();
+            *min = (*min).max(*count);
+        }
+        for (letter, count) in &other.max_counts {
+            let max = self.max_counts.entry(*letter).or_insert(*count);
+            *max = (*max).min(*count);
+        }
+    }
+
+    /// Whether a (normalized) word satisfies every constraint
+    pub fn allows(&self, word: &str) -> bool {
+        if self.greens.is_empty() {
+            return true;
+        }
+        if word_length(word) != self.greens.len() {
+            return false;
+        }
+
+        let mut counts: BTreeMap<char, usize> = BTreeMap::new();
+        for (position, letter) in word.chars().enumerate() {
+            if self.greens[position].is_some_and(|green| green != letter) {
+                return false;
+            }
+            if self
+                .excluded
+                .get(&letter)
+                .is_some_and(|positions| positions.contains(&position))
+            {
+                return false;
+            }
+            *counts.entry(letter).or_default() += 1;
+        }
+
+        let count = |letter: &char| counts.get(letter).copied().unwrap_or(0);
+        self.min_counts
+            .iter()
+            .all(|(letter, min)| count(letter) >= *min)
+            && self
+                .max_counts
+                .iter()
+                .all(|(letter, max)| count(letter) <= *max)
+    }
+
+    fn check_length(&self, length: usize) -> PyResult<()> {
+        match self.word_length() {
+            Some(expected) if expected != length => {
+                Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                    "Constraints are for {}-letter words, got a {}-letter hint",
+                    expected, length
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Known letter at a position, if it has been revealed green
+    pub fn green_at(&self, position: usize) -> Option<char> {
+        self.greens.get(position).copied().flatten()
+    }
+
+    pub fn min_count(&self, letter: char) -> usize {
+        self.min_counts.get(&letter).copied().unwrap_or(0)
+    }
+
+    pub fn max_count(&self, letter: char) -> Option<usize> {
+        self.max_counts.get(&letter).copied()
+    }
+
+    pub fn is_excluded(&self, letter: char, position: usize) -> bool {
+        self.excluded
+            .get(&letter)
+            .is_some_and(|positions| positions.contains(&position))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consistency::fits_hint;
+    use crate::corpus::Corpus;
+    use crate::grade::grade_guess;
+
+    /// allows() must agree with regrading every corpus word against the history
+    fn assert_matches_regrading(history: &[WordleHint]) {
+        let constraints = Constraints::from_hints(history).unwrap();
+        for word in Corpus::embedded().guesses() {
+            let fits = history.iter().all(|hint| fits_hint(word, hint));
+            assert_eq!(constraints.allows(word), fits, "{} vs {:?}", word, history);
+        }
+    }
+
+    #[test]
+    fn test_duplicate_letter_counts() {
+        // allee vs apple: 'l' shown once and grayed once, 'e' green once and grayed once
+        let constraints = Constraints::from_hints(&[grade_guess("allee", "apple")]).unwrap();
+        assert_eq!(constraints.min_count('l'), 1);
+        assert_eq!(constraints.max_count('l'), Some(1));
+        assert_eq!(constraints.max_count('e'), Some(1));
+        assert!(constraints.allows("apple"));
+
+        // babee vs aback: one b yellow and one gray, so exactly one b; e is absent
+        let constraints = Constraints::from_hints(&[grade_guess("babee", "aback")]).unwrap();
+        assert_eq!(constraints.min_count('b'), 1);
+        assert_eq!(constraints.max_count('b'), Some(1));
+        assert_eq!(constraints.max_count('e'), Some(0));
+        assert!(constraints.allows("aback"));
+        assert!(constraints.is_excluded('b', 0));
+    }
+
+    #[test]
+    fn test_matches_regrading_on_corpus() {
+        assert_matches_regrading(&[grade_guess("allee", "apple")]);
+        assert_matches_regrading(&[grade_guess("babee", "aback")]);
+        assert_matches_regrading(&[grade_guess("speed", "abide"), grade_guess("geese", "abide")]);
+        assert_matches_regrading(&[grade_guess("crane", "cigar"), grade_guess("robot", "cigar")]);
+    }
+
+    #[test]
+    fn test_other_lengths() {
+        let history = [grade_guess("allele", "parley")];
+        let constraints = Constraints::from_hints(&history).unwrap();
+        assert!(constraints.allows("parley"));
+        assert_eq!(constraints.max_count('l'), Some(1));
+        assert_eq!(constraints.max_count('e'), Some(1));
+        assert!(!constraints.allows("crane"));
+
+        let constraints = Constraints::from_hints(&[grade_guess("letters", "settler")]).unwrap();
+        assert!(constraints.allows("settler"));
+        assert_eq!(constraints.min_count('t'), 2);
+    }
+
+    #[test]
+    fn test_merge_equals_folding_all_hints() {
+        let first = [grade_guess("crane", "rotor")];
+        let second = [grade_guess("robot", "rotor"), grade_guess("motor", "rotor")];
+        let mut merged = Constraints::from_hints(&first).unwrap();
+        merged.merge(&Constraints::from_hints(&second).unwrap());
+        let all: Vec<WordleHint> = first.iter().chain(&second).cloned().collect();
+        assert_eq!(merged, Constraints::from_hints(&all).unwrap());
+    }
+
+    #[test]
+    fn test_mixed_lengths_rejected() {
+        let hints = [
+            grade_guess("crane", "cigar"),
+            grade_guess("planet", "parley"),
+        ];
+        let err = Constraints::from_hints(&hints).unwrap_err();
+        assert_eq!((err.found, err.expected), (6, 5));
+
+        let mut constraints = Constraints::from_hints(&hints[..1]).unwrap();
+        assert!(constraints.add_hint(&hints[1]).is_err());
+        assert_eq!(constraints, Constraints::from_hints(&hints[..1]).unwrap());
+    }
+}
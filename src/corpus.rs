@@ -1,31 +1,342 @@
 use crate::common::WORD_LENGTH;
+use crate::letter_index::LetterIndex;
+use crate::normalize::{normalize_word, word_length};
+use crate::utils::fnv1a_hash;
+use pyo3::prelude::*;
 use std::collections::HashSet;
-use std::sync::OnceLock;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::{Arc, OnceLock};
 
 pub const ALL_WORDS_LIST: &str = include_str!("../word-lists/corpus.txt");
 pub const ANSWER_KEY_LIST: &str = include_str!("../word-lists/possible_answers.txt");
 
 static CORPUS: OnceLock<HashSet<&'static str>> = OnceLock::new();
 static GRADING_ANSWER_KEY: OnceLock<Vec<&'static str>> = OnceLock::new();
+static EMBEDDED_CORPUS: OnceLock<Corpus> = OnceLock::new();
 
+#[deprecated(note = "use Corpus::embedded().guesses()")]
 pub fn get_corpus() -> &'static HashSet<&'static str> {
-    CORPUS.get_or_init(|| ALL_WORDS_LIST.lines().collect())
+    CORPUS.get_or_init(|| {
+        embedded_corpus()
+            .guesses()
+            .iter()
+            .map(String::as_str)
+            .collect()
+    })
 }
 
+#[deprecated(note = "use Corpus::embedded().answers_of_length(WORD_LENGTH)")]
 pub fn get_grading_answer_key() -> &'static Vec<&'static str> {
-    GRADING_ANSWER_KEY.get_or_init(|| {
-        ANSWER_KEY_LIST
-            .lines()
-            .filter(|word| word.len() == WORD_LENGTH)
-            .collect()
-    })
+    GRADING_ANSWER_KEY.get_or_init(|| embedded_corpus().answers_of_length(WORD_LENGTH))
 }
 
+#[deprecated(note = "use Corpus::embedded().is_valid_word()")]
 pub fn is_valid_word(word: &str) -> bool {
-    get_corpus().contains(word)
+    embedded_corpus().is_valid_word(word)
+}
+
+/// The guess list and answer key a tournament is played with. Words are stored normalized
+/// (see normalize_word), and every answer is also a valid guess. Cloning is cheap: clones
+/// share the same lists
+#[pyclass(frozen, module = "wordle_tournament_lib")]
+#[derive(Clone)]
+pub struct Corpus {
+    lists: Arc<WordLists>,
+}
+
+struct WordLists {
+    guesses: Vec<String>,
+    valid_words: HashSet<String>,
+    answers: Vec<String>,
+    strip_accents: bool,
+    keep_accented: String,
+    guesses_hash: u64,
+    answers_hash: u64,
+    /// Built on first use, see Corpus::letter_index
+    guess_index: OnceLock<LetterIndex>,
+    answer_index: OnceLock<LetterIndex>,
+}
+
+#[pymethods]
+impl Corpus {
+    /// Build a corpus from in-memory iterables of words, e.g. lists or generators
+    #[new]
+    #[pyo3(signature = (guesses, answers, strip_accents = false, keep_accented = ""))]
+    fn new_corpus(
+        guesses: &Bound<'_, PyAny>,
+        answers: &Bound<'_, PyAny>,
+        strip_accents: bool,
+        keep_accented: &str,
+    ) -> PyResult<Self> {
+        let collect = |words: &Bound<'_, PyAny>| -> PyResult<Vec<String>> {
+            words.try_iter()?.map(|word| word?.extract()).collect()
+        };
+        Ok(Corpus::new(
+            collect(guesses)?,
+            collect(answers)?,
+            strip_accents,
+            keep_accented,
+        ))
+    }
+
+    /// The word lists compiled into the library
+    #[staticmethod]
+    #[pyo3(name = "embedded")]
+    fn embedded_py() -> Self {
+        Corpus::embedded()
+    }
+
+    /// Load word lists from UTF-8 files with one word per line. Words and guesses are
+    /// NFC-normalized; with strip_accents diacritics are also removed, except on the letters
+    /// listed in keep_accented
+    #[staticmethod]
+    #[pyo3(name = "from_files")]
+    #[pyo3(signature = (guesses_path, answers_path, strip_accents = false, keep_accented = ""))]
+    pub fn from_files_py(
+        guesses_path: &str,
+        answers_path: &str,
+        strip_accents: bool,
+        keep_accented: &str,
+    ) -> PyResult<Self> {
+        Corpus::from_files(guesses_path, answers_path, strip_accents, keep_accented).map_err(|e| {
+            PyErr::new::<pyo3::exceptions::PyIOError, _>(format!(
+                "Failed to load word lists: {}",
+                e
+            ))
+        })
+    }
+
+    /// Whether a word is a valid guess, after normalizing it the way guesses are
+    #[pyo3(name = "is_valid_word")]
+    fn is_valid_word_py(&self, word: &str) -> bool {
+        self.is_valid_word(&self.normalize(word))
+    }
+
+    #[pyo3(name = "normalize")]
+    fn normalize_py(&self, word: &str) -> String {
+        self.normalize(word)
+    }
+
+    /// Every guess list word, in file order
+    #[getter(guesses)]
+    fn guesses_py(&self) -> Vec<String> {
+        self.guesses().to_vec()
+    }
+
+    /// Every answer list word, in file order
+    #[getter(answers)]
+    fn answers_py(&self) -> Vec<String> {
+        self.answers().to_vec()
+    }
+
+    #[pyo3(name = "guesses_of_length")]
+    fn guesses_of_length_py(&self, length: usize) -> Vec<String> {
+        to_strings(self.guesses_of_length(length))
+    }
+
+    #[pyo3(name = "answers_of_length")]
+    fn answers_of_length_py(&self, length: usize) -> Vec<String> {
+        to_strings(self.answers_of_length(length))
+    }
+
+    /// Content hash of the guess list, see list_hash
+    #[getter]
+    pub fn guesses_hash(&self) -> u64 {
+        self.lists.guesses_hash
+    }
+
+    /// Content hash of the answer list, see list_hash
+    #[getter]
+    pub fn answers_hash(&self) -> u64 {
+        self.lists.answers_hash
+    }
+
+    /// Letter-position index over the answer list (or the guess list, with use_guesses).
+    /// Query results are indices into that list
+    #[pyo3(name = "letter_index")]
+    #[pyo3(signature = (use_guesses = false))]
+    fn letter_index_py(&self, use_guesses: bool) -> LetterIndex {
+        self.letter_index(use_guesses).clone()
+    }
+
+    fn __contains__(&self, word: &str) -> bool {
+        self.is_valid_word_py(word)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Corpus(guesses={}, answers={})",
+            self.lists.guesses.len(),
+            self.lists.answers.len()
+        )
+    }
+}
+
+impl Corpus {
+    pub fn new(
+        guesses: impl IntoIterator<Item = impl AsRef<str>>,
+        answers: impl IntoIterator<Item = impl AsRef<str>>,
+        strip_accents: bool,
+        keep_accented: &str,
+    ) -> Self {
+        let normalize = |words: Vec<String>| -> Vec<String> {
+            let mut seen = HashSet::new();
+            words
+                .iter()
+                .map(|word| normalize_word(word, strip_accents, keep_accented))
+                .filter(|word| !word.is_empty() && seen.insert(word.clone()))
+                .collect()
+        };
+        let guesses = normalize(
+            guesses
+                .into_iter()
+                .map(|w| w.as_ref().to_string())
+                .collect(),
+        );
+        let answers = normalize(
+            answers
+                .into_iter()
+                .map(|w| w.as_ref().to_string())
+                .collect(),
+        );
+        let valid_words = guesses.iter().chain(answers.iter()).cloned().collect();
+
+        Corpus {
+            lists: Arc::new(WordLists {
+                guesses_hash: list_hash(&guesses),
+                answers_hash: list_hash(&answers),
+                guesses,
+                valid_words,
+                answers,
+                strip_accents,
+                keep_accented: keep_accented.to_string(),
+                guess_index: OnceLock::new(),
+                answer_index: OnceLock::new(),
+            }),
+        }
+    }
+
+    /// The word lists compiled into the library
+    pub fn embedded() -> Corpus {
+        embedded_corpus().clone()
+    }
+
+    /// Load word lists from UTF-8 files with one word per line. Blank lines are skipped
+    pub fn from_files<P: AsRef<Path>>(
+        guesses_path: P,
+        answers_path: P,
+        strip_accents: bool,
+        keep_accented: &str,
+    ) -> io::Result<Self> {
+        let guesses = fs::read_to_string(guesses_path)?;
+        let answers = fs::read_to_string(answers_path)?;
+        Ok(Corpus::new(
+            guesses.lines(),
+            answers.lines(),
+            strip_accents,
+            keep_accented,
+        ))
+    }
+
+    /// Put a word into the same normalized form the lists are stored in
+    pub fn normalize(&self, word: &str) -> String {
+        normalize_word(word, self.lists.strip_accents, &self.lists.keep_accented)
+    }
+
+    /// Whether a (normalized) word is a valid guess
+    pub fn is_valid_word(&self, word: &str) -> bool {
+        self.lists.valid_words.contains(word)
+    }
+
+    /// Every guess list word, in file order
+    pub fn guesses(&self) -> &[String] {
+        &self.lists.guesses
+    }
+
+    /// Every answer key word, in file order
+    pub fn answers(&self) -> &[String] {
+        &self.lists.answers
+    }
+
+    /// Guess list words of the given length, in file order
+    pub fn guesses_of_length(&self, length: usize) -> Vec<&str> {
+        of_length(&self.lists.guesses, length)
+    }
+
+    /// Answer key words of the given length, in file order
+    pub fn answers_of_length(&self, length: usize) -> Vec<&str> {
+        of_length(&self.lists.answers, length)
+    }
+
+    /// Letter-position index over the answer list (or the guess list, with use_guesses),
+    /// built the first time it is asked for and shared by every clone of the corpus
+    pub fn letter_index(&self, use_guesses: bool) -> &LetterIndex {
+        match use_guesses {
+            true => self
+                .lists
+                .guess_index
+                .get_or_init(|| LetterIndex::new(&self.lists.guesses)),
+            false => self
+                .lists
+                .answer_index
+                .get_or_init(|| LetterIndex::new(&self.lists.answers)),
+        }
+    }
+}
+
+/// 64-bit FNV-1a of the words joined by newlines, in UTF-8. Equal to hashing a one word per
+/// line file of the same list without a trailing newline, so local copies can be checked
+pub fn list_hash(words: &[String]) -> u64 {
+    fnv1a_hash(words.join("\n").as_bytes())
+}
+
+/// Every valid guess of the embedded corpus, in file order. These are the words the
+/// evaluator accepts by default
+#[pyfunction]
+pub fn guess_corpus() -> Vec<String> {
+    Corpus::embedded().guesses().to_vec()
+}
+
+/// Every word of the embedded answer list, in file order
+#[pyfunction]
+pub fn answer_list() -> Vec<String> {
+    Corpus::embedded().answers().to_vec()
+}
+
+/// Whether the evaluator accepts a word as a guess with the embedded corpus
+#[pyfunction(name = "is_valid_word")]
+pub fn is_valid_word_py(word: &str) -> bool {
+    Corpus::embedded().is_valid_word_py(word)
+}
+
+/// (guess corpus hash, answer list hash) of the embedded word lists, see list_hash
+#[pyfunction]
+pub fn word_list_hashes() -> (u64, u64) {
+    let corpus = Corpus::embedded();
+    (corpus.guesses_hash(), corpus.answers_hash())
+}
+
+fn to_strings(words: Vec<&str>) -> Vec<String> {
+    words.into_iter().map(str::to_string).collect()
+}
+
+fn embedded_corpus() -> &'static Corpus {
+    EMBEDDED_CORPUS
+        .get_or_init(|| Corpus::new(ALL_WORDS_LIST.lines(), ANSWER_KEY_LIST.lines(), false, ""))
+}
+
+fn of_length(words: &[String], length: usize) -> Vec<&str> {
+    words
+        .iter()
+        .filter(|word| word_length(word) == length)
+        .map(|word| word.as_str())
+        .collect()
 }
 
 #[cfg(test)]
+#[allow(deprecated)]
 mod tests {
     use super::*;
 
@@ -50,6 +361,69 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_guesses_are_valid_words() {
+        let corpus = Corpus::embedded();
+        assert!(corpus
+            .guesses()
+            .iter()
+            .all(|word| corpus.is_valid_word(word)));
+        assert!(corpus
+            .answers()
+            .iter()
+            .all(|word| corpus.is_valid_word(word)));
+    }
+
+    #[test]
+    fn test_lists_for_length() {
+        let corpus = Corpus::embedded();
+        assert_eq!(
+            corpus.guesses_of_length(WORD_LENGTH).len(),
+            corpus.guesses().len()
+        );
+        assert!(corpus.guesses_of_length(WORD_LENGTH + 1).is_empty());
+        assert!(corpus.answers_of_length(WORD_LENGTH + 1).is_empty());
+    }
+
+    #[test]
+    fn test_embedded_corpus_matches_lists() {
+        let corpus = Corpus::embedded();
+        let guesses: Vec<&str> = ALL_WORDS_LIST.lines().collect();
+        let answers: Vec<&str> = ANSWER_KEY_LIST
+            .lines()
+            .filter(|word| word_length(word) == WORD_LENGTH)
+            .collect();
+        assert_eq!(corpus.guesses_of_length(WORD_LENGTH), guesses);
+        assert_eq!(corpus.answers_of_length(WORD_LENGTH), answers);
+        assert_eq!(
+            &corpus.answers_of_length(WORD_LENGTH),
+            get_grading_answer_key()
+        );
+        assert!(corpus.is_valid_word("crane"));
+        assert!(!corpus.is_valid_word("zzzzz"));
+    }
+
+    #[test]
+    fn test_corpus_normalizes_unicode() {
+        let corpus = Corpus::new(["nin\u{0303}os", "ÄPFEL"], ["canción"], true, "ñ");
+        assert!(corpus.is_valid_word("niños"));
+        assert!(corpus.is_valid_word("APFEL"));
+        // Answers are valid guesses even when missing from the guess list
+        assert!(corpus.is_valid_word("cancion"));
+        assert_eq!(corpus.guesses_of_length(5), ["niños", "APFEL"]);
+        assert_eq!(corpus.answers_of_length(7), ["cancion"]);
+        assert_eq!(corpus.normalize("cancio\u{0301}n"), "cancion");
+    }
+
+    #[test]
+    fn test_list_hashes_match_files() {
+        let corpus = Corpus::embedded();
+        let file_hash = |list: &str| fnv1a_hash(list.trim_end_matches('\n').as_bytes());
+        assert_eq!(corpus.guesses_hash(), file_hash(ALL_WORDS_LIST));
+        assert_eq!(corpus.answers_hash(), file_hash(ANSWER_KEY_LIST));
+        assert_ne!(corpus.guesses_hash(), corpus.answers_hash());
+    }
+
     #[test]
     fn test_is_valid_word() {
         assert!(is_valid_word("crane"));
@@ -0,0 +1,113 @@
+use crate::hint::{HintType, WordleHint};
+use crate::normalize::{normalize_word, word_length};
+use crate::utils::py_print;
+use pyo3::prelude::*;
+
+/// Count-only feedback for a guess, as in Jotto or Mastermind's black/white pegs: how many
+/// letters are in the correct position and how many are present elsewhere, but not which
+#[pyclass]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CountHint {
+    #[pyo3(get)]
+    word: String,
+    #[pyo3(get)]
+    correct: usize,
+    #[pyo3(get)]
+    present: usize,
+}
+
+#[pymethods]
+impl CountHint {
+    #[new]
+    pub fn new_count_hint(word: String, correct: usize, present: usize) -> PyResult<Self> {
+        let word = normalize_word(&word, false, "");
+        if correct + present > word_length(&word) {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Word {} has {} letters, but correct + present = {}",
+                word,
+                word_length(&word),
+                correct + present
+            )));
+        }
+        Ok(CountHint::new(word, correct, present))
+    }
+
+    /// Number of letters that are not in the answer at all (after accounting for duplicates)
+    #[getter]
+    pub fn absent(&self) -> usize {
+        word_length(&self.word) - self.correct - self.present
+    }
+
+    pub fn is_fully_correct(&self) -> bool {
+        self.correct == word_length(&self.word)
+    }
+
+    pub fn visualize_hint(&self, py: Python) -> PyResult<()> {
+        let letters: Vec<String> = self
+            .word
+            .chars()
+            .map(|letter| letter.to_uppercase().to_string())
+            .collect();
+        let output = format!(
+            "{}\n🟩 x{} 🟨 x{}",
+            letters.join(" "),
+            self.correct,
+            self.present
+        );
+        py_print(py, &output)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "CountHint(word='{}', correct={}, present={})",
+            self.word, self.correct, self.present
+        )
+    }
+}
+
+impl CountHint {
+    pub fn new(word: String, correct: usize, present: usize) -> Self {
+        CountHint {
+            word,
+            correct,
+            present,
+        }
+    }
+
+    /// Collapse positional feedback into counts
+    pub fn from_hint(hint: &WordleHint) -> Self {
+        let count = |hint_type: HintType| {
+            hint.hint_types()
+                .iter()
+                .filter(|h| **h == hint_type)
+                .count()
+        };
+        CountHint::new(
+            hint.word().to_string(),
+            count(HintType::Correct),
+            count(HintType::Present),
+        )
+    }
+
+    pub fn word(&self) -> &str {
+        &self.word
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grade::grade_guess;
+
+    #[test]
+    fn test_from_hint_counts_duplicates_like_grade_guess() {
+        let counts = CountHint::from_hint(&grade_guess("allee", "apple"));
+        assert_eq!((counts.correct, counts.present, counts.absent()), (2, 1, 2));
+
+        let counts = CountHint::from_hint(&grade_guess("babee", "aback"));
+        assert_eq!((counts.correct, counts.present, counts.absent()), (0, 2, 3));
+        assert!(!counts.is_fully_correct());
+
+        assert!(CountHint::from_hint(&grade_guess("crane", "crane")).is_fully_correct());
+    }
+}
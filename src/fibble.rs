@@ -0,0 +1,115 @@
+use crate::grade::{grade_guess, Grader};
+use crate::hint::{HintType, WordleHint};
+use crate::utils::SplitMix64;
+
+/// Fibble-style grader: grades against a fixed answer, then flips exactly lies_per_row
+/// tiles of every row to a wrong hint, chosen by a seeded RNG. A correct guess is always
+/// reported truthfully so the game can end, and a lie never makes a wrong guess's row fully
+/// correct. The positions of every lie are recorded
+pub struct LyingGrader {
+    answer: String,
+    lies_per_row: usize,
+    rng: SplitMix64,
+    lies: Vec<Vec<usize>>,
+}
+
+impl LyingGrader {
+    pub fn new(answer: String, lies_per_row: usize, seed: u64) -> Self {
+        LyingGrader {
+            answer,
+            lies_per_row,
+            rng: SplitMix64::new(seed),
+            lies: vec![],
+        }
+    }
+
+    /// Lied-about tile positions (0-based) for each row graded so far, in order
+    pub fn lies(&self) -> &[Vec<usize>] {
+        &self.lies
+    }
+}
+
+impl Grader for LyingGrader {
+    fn grade(&mut self, guess: &str) -> WordleHint {
+        let truth = grade_guess(guess, &self.answer);
+        if truth.is_fully_correct() {
+            self.lies.push(vec![]);
+            return truth;
+        }
+
+        let mut hints = truth.hint_types().to_vec();
+        let mut positions: Vec<usize> = (0..hints.len()).collect();
+        self.rng.shuffle(&mut positions);
+        let mut lie_positions: Vec<usize> = positions
+            .into_iter()
+            .take(self.lies_per_row.min(hints.len()))
+            .collect();
+        lie_positions.sort();
+
+        for position in &lie_positions {
+            // Lying Correct on the only tile that isn't Correct would end a game that wasn't won
+            let completes_row = hints
+                .iter()
+                .enumerate()
+                .all(|(i, hint)| i == *position || *hint == HintType::Correct);
+            let alternatives: Vec<HintType> =
+                [HintType::Correct, HintType::Present, HintType::Absent]
+                    .into_iter()
+                    .filter(|hint| *hint != hints[*position])
+                    .filter(|hint| !(completes_row && *hint == HintType::Correct))
+                    .collect();
+            hints[*position] = alternatives[self.rng.below(alternatives.len())];
+        }
+
+        self.lies.push(lie_positions);
+        WordleHint::new(guess.to_string(), hints)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exactly_k_lies_per_row() {
+        let mut grader = LyingGrader::new("cigar".to_string(), 2, 7);
+        for guess in ["crane", "robot", "civic"] {
+            let truth = grade_guess(guess, "cigar");
+            let hint = grader.grade(guess);
+            let wrong: Vec<usize> = (0..5)
+                .filter(|i| hint.hint_types()[*i] != truth.hint_types()[*i])
+                .collect();
+            assert_eq!(&wrong, grader.lies().last().unwrap());
+            assert_eq!(wrong.len(), 2);
+        }
+    }
+
+    #[test]
+    fn test_correct_guess_is_truthful() {
+        let mut grader = LyingGrader::new("cigar".to_string(), 1, 0);
+        assert!(grader.grade("cigar").is_fully_correct());
+        assert!(grader.lies()[0].is_empty());
+    }
+
+    #[test]
+    fn test_lie_never_completes_wrong_guess() {
+        // "crane" is one tile off "crank", so a Correct lie on that tile would read as solved
+        for seed in 0..200 {
+            let mut grader = LyingGrader::new("crank".to_string(), 1, seed);
+            assert!(!grader.grade("crane").is_fully_correct());
+        }
+    }
+
+    #[test]
+    fn test_same_seed_same_lies() {
+        let run = |seed| {
+            let mut grader = LyingGrader::new("cigar".to_string(), 1, seed);
+            let hints: Vec<String> = ["crane", "robot", "civic", "sugar"]
+                .iter()
+                .map(|guess| grader.grade(guess).hints())
+                .collect();
+            (hints, grader.lies().to_vec())
+        };
+        assert_eq!(run(3), run(3));
+    }
+}
@@ -0,0 +1,390 @@
+use crate::corpus::Corpus;
+use crate::normalize::normalize_word;
+use pyo3::prelude::*;
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// How raw frequencies are turned into prior weights before normalizing
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PriorSmoothing {
+    /// Weights proportional to the counts
+    Raw,
+    /// ln(1 + count), flattening the gap between common and rare words
+    Log,
+    /// A logistic step in log10(count) centered on `threshold`: words well above it weigh
+    /// about 1 and words well below about 0. `width` is the step's scale in decades
+    Sigmoid { threshold: f64, width: f64 },
+}
+
+impl PriorSmoothing {
+    /// Smoothing from Python-facing options. The sigmoid threshold defaults to the median
+    /// nonzero count of the words being weighted
+    pub fn from_options(
+        smoothing: &str,
+        threshold: Option<f64>,
+        width: f64,
+        counts: &[f64],
+    ) -> PyResult<Self> {
+        match smoothing {
+            "raw" => Ok(PriorSmoothing::Raw),
+            "log" => Ok(PriorSmoothing::Log),
+            "sigmoid" => {
+                let threshold = threshold.unwrap_or_else(|| median_nonzero(counts));
+                PriorSmoothing::sigmoid(threshold, width).ok_or_else(|| {
+                    PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                        "Sigmoid threshold and width must be positive finite numbers",
+                    )
+                })
+            }
+            _ => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Unknown smoothing '{}' - must be 'raw', 'log' or 'sigmoid'",
+                smoothing
+            ))),
+        }
+    }
+
+    /// Sigmoid smoothing, or None unless threshold and width are positive and finite
+    pub fn sigmoid(threshold: f64, width: f64) -> Option<Self> {
+        let valid = |value: f64| value.is_finite() && value > 0.0;
+        (valid(threshold) && valid(width)).then_some(PriorSmoothing::Sigmoid { threshold, width })
+    }
+
+    fn weight(self, count: f64) -> f64 {
+        match self {
+            PriorSmoothing::Raw => count,
+            PriorSmoothing::Log => count.ln_1p(),
+            PriorSmoothing::Sigmoid { threshold, width } => {
+                // log10(0) is -inf, which correctly maps unseen words to 0
+                let z = (count.log10() - threshold.log10()) / width;
+                1.0 / (1.0 + (-z).exp())
+            }
+        }
+    }
+}
+
+/// Why a frequency list could not be read
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrequencyParseError {
+    /// 1-based line number
+    pub line: usize,
+    pub content: String,
+}
+
+impl fmt::Display for FrequencyParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Line {} of the frequency list is not 'word count': '{}'",
+            self.line, self.content
+        )
+    }
+}
+
+impl From<FrequencyParseError> for PyErr {
+    fn from(err: FrequencyParseError) -> PyErr {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(err.to_string())
+    }
+}
+
+/// Per-word frequency counts, used to weight answers by how likely they are to be picked.
+/// Words are stored NFC-normalized so they line up with Corpus words; counts of words that
+/// appear twice are added. No table is embedded yet: the library has no suitably licensed
+/// frequency list to compile in, so callers load their own
+#[pyclass(frozen, module = "wordle_tournament_lib")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FrequencyTable {
+    counts: HashMap<String, f64>,
+}
+
+#[pymethods]
+impl FrequencyTable {
+    /// Table from an in-memory mapping of word to count
+    #[new]
+    fn new_frequency_table(counts: HashMap<String, f64>) -> PyResult<Self> {
+        if let Some((word, count)) = counts.iter().find(|(_, count)| !is_valid_count(**count)) {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Count for '{}' must be a non-negative number, got {}",
+                word, count
+            )));
+        }
+        Ok(FrequencyTable::new(counts))
+    }
+
+    /// Parse 'word count' lines; see FrequencyTable.parse
+    #[staticmethod]
+    #[pyo3(name = "from_text")]
+    fn from_text_py(text: &str) -> PyResult<Self> {
+        Ok(FrequencyTable::parse(text)?)
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_file")]
+    fn from_file_py(path: &str) -> PyResult<Self> {
+        FrequencyTable::from_file(path).map_err(|e| match e.kind() {
+            io::ErrorKind::InvalidData => {
+                PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string())
+            }
+            _ => PyErr::new::<pyo3::exceptions::PyIOError, _>(format!(
+                "Failed to load frequency list {}: {}",
+                path, e
+            )),
+        })
+    }
+
+    /// Count for a word, or None if the table doesn't have it
+    #[pyo3(name = "count")]
+    fn count_py(&self, word: &str) -> Option<f64> {
+        self.count(&normalize_word(word, false, ""))
+    }
+
+    /// Prior weights for the words, in order, summing to 1. Words missing from the table
+    /// count as 0; if every weight is 0 the prior is uniform
+    #[pyo3(name = "priors")]
+    #[pyo3(signature = (words, smoothing = "raw", threshold = None, width = 0.5))]
+    fn priors_py(
+        &self,
+        words: Vec<String>,
+        smoothing: &str,
+        threshold: Option<f64>,
+        width: f64,
+    ) -> PyResult<Vec<f64>> {
+        let words: Vec<String> = words
+            .iter()
+            .map(|word| normalize_word(word, false, ""))
+            .collect();
+        self.priors_with_options(&words, smoothing, threshold, width)
+    }
+
+    /// Prior weights aligned with a corpus's answer list (or its guess list, with
+    /// use_guesses), the embedded corpus by default. Table words are normalized the way the
+    /// corpus normalizes its lists before matching
+    #[pyo3(signature = (
+        corpus = None,
+        use_guesses = false,
+        smoothing = "raw",
+        threshold = None,
+        width = 0.5
+    ))]
+    fn corpus_priors(
+        &self,
+        corpus: Option<Corpus>,
+        use_guesses: bool,
+        smoothing: &str,
+        threshold: Option<f64>,
+        width: f64,
+    ) -> PyResult<Vec<f64>> {
+        let corpus = corpus.unwrap_or_else(Corpus::embedded);
+        let words = match use_guesses {
+            true => corpus.guesses(),
+            false => corpus.answers(),
+        };
+        self.for_corpus(&corpus)
+            .priors_with_options(words, smoothing, threshold, width)
+    }
+
+    fn __len__(&self) -> usize {
+        self.counts.len()
+    }
+
+    fn __contains__(&self, word: &str) -> bool {
+        self.count_py(word).is_some()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("FrequencyTable(words={})", self.counts.len())
+    }
+}
+
+impl FrequencyTable {
+    pub fn new(counts: impl IntoIterator<Item = (impl AsRef<str>, f64)>) -> Self {
+        let mut table = FrequencyTable::default();
+        for (word, count) in counts {
+            *table
+                .counts
+                .entry(normalize_word(word.as_ref(), false, ""))
+                .or_default() += count;
+        }
+        table
+    }
+
+    /// Parse one 'word count' pair per line, separated by whitespace or a comma. Blank lines
+    /// and lines starting with '#' are skipped
+    pub fn parse(text: &str) -> Result<Self, FrequencyParseError> {
+        let mut counts = vec![];
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let error = || FrequencyParseError {
+                line: i + 1,
+                content: line.to_string(),
+            };
+            let mut fields = line
+                .split(|c: char| c.is_whitespace() || c == ',')
+                .filter(|field| !field.is_empty());
+            let (Some(word), Some(count), None) = (fields.next(), fields.next(), fields.next())
+            else {
+                return Err(error());
+            };
+            match count.parse::<f64>() {
+                Ok(count) if is_valid_count(count) => counts.push((word, count)),
+                _ => return Err(error()),
+            }
+        }
+        Ok(FrequencyTable::new(counts))
+    }
+
+    /// Read a frequency list file. Parse errors are reported as InvalidData
+    pub fn from_file<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let text = fs::read_to_string(path)?;
+        FrequencyTable::parse(&text)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))
+    }
+
+    /// The table with its words normalized the way the corpus normalizes its lists (which
+    /// may strip accents), so it can be looked up with corpus words
+    pub fn for_corpus(&self, corpus: &Corpus) -> FrequencyTable {
+        let mut table = FrequencyTable::default();
+        for (word, count) in &self.counts {
+            *table.counts.entry(corpus.normalize(word)).or_default() += count;
+        }
+        table
+    }
+
+    /// Count for a (normalized) word
+    pub fn count(&self, word: &str) -> Option<f64> {
+        self.counts.get(word).copied()
+    }
+
+    /// Counts for each word in order, 0 for words missing from the table
+    pub fn counts_for<S: AsRef<str>>(&self, words: &[S]) -> Vec<f64> {
+        words
+            .iter()
+            .map(|word| self.count(word.as_ref()).unwrap_or(0.0))
+            .collect()
+    }
+
+    /// Prior weights for (normalized) words, in order, summing to 1. If every weight is 0
+    /// the prior is uniform
+    pub fn priors<S: AsRef<str>>(&self, words: &[S], smoothing: PriorSmoothing) -> Vec<f64> {
+        let weights: Vec<f64> = self
+            .counts_for(words)
+            .into_iter()
+            .map(|count| smoothing.weight(count))
+            .collect();
+        let total: f64 = weights.iter().sum();
+        match total > 0.0 {
+            true => weights.iter().map(|weight| weight / total).collect(),
+            false => vec![1.0 / words.len() as f64; words.len()],
+        }
+    }
+
+    /// priors() with smoothing given as Python-facing options
+    pub fn priors_with_options<S: AsRef<str>>(
+        &self,
+        words: &[S],
+        smoothing: &str,
+        threshold: Option<f64>,
+        width: f64,
+    ) -> PyResult<Vec<f64>> {
+        let smoothing =
+            PriorSmoothing::from_options(smoothing, threshold, width, &self.counts_for(words))?;
+        Ok(self.priors(words, smoothing))
+    }
+}
+
+fn is_valid_count(count: f64) -> bool {
+    count.is_finite() && count >= 0.0
+}
+
+/// Median of the nonzero counts, or 1 if there are none
+fn median_nonzero(counts: &[f64]) -> f64 {
+    let mut nonzero: Vec<f64> = counts.iter().copied().filter(|c| *c > 0.0).collect();
+    if nonzero.is_empty() {
+        return 1.0;
+    }
+    nonzero.sort_by(f64::total_cmp);
+    nonzero[nonzero.len() / 2]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "# word count\ncrane 300\ncigar,100\n\nrobot\t0\ncrane 100\n";
+
+    #[test]
+    fn test_parse() {
+        let table = FrequencyTable::parse(TEXT).unwrap();
+        assert_eq!(table.count("crane"), Some(400.0));
+        assert_eq!(table.count("cigar"), Some(100.0));
+        assert_eq!(table.count("robot"), Some(0.0));
+        assert_eq!(table.count("rotor"), None);
+
+        assert_eq!(
+            FrequencyTable::parse("crane 3\ncigar many"),
+            Err(FrequencyParseError {
+                line: 2,
+                content: "cigar many".to_string()
+            })
+        );
+        assert!(FrequencyTable::parse("crane -1").is_err());
+        assert!(FrequencyTable::parse("crane 1 2").is_err());
+    }
+
+    #[test]
+    fn test_smoothing() {
+        let table = FrequencyTable::parse(TEXT).unwrap();
+        let words = ["crane", "cigar", "robot", "rotor"];
+
+        let raw = table.priors(&words, PriorSmoothing::Raw);
+        assert_eq!(raw, [0.8, 0.2, 0.0, 0.0]);
+
+        let log = table.priors(&words, PriorSmoothing::Log);
+        assert!((log.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+        assert!(log[0] > log[1] && log[0] / log[1] < 4.0);
+
+        let sigmoid = PriorSmoothing::Sigmoid {
+            threshold: 200.0,
+            width: 0.1,
+        };
+        let weights = table.priors(&words, sigmoid);
+        assert!(weights[0] > 0.9 && weights[1] < 0.1);
+        assert_eq!(weights[2], 0.0);
+    }
+
+    #[test]
+    fn test_sigmoid_rejects_bad_parameters() {
+        assert!(PriorSmoothing::sigmoid(200.0, 0.5).is_some());
+        assert!(PriorSmoothing::sigmoid(200.0, 0.0).is_none());
+        assert!(PriorSmoothing::sigmoid(-1.0, 0.5).is_none());
+        assert!(PriorSmoothing::sigmoid(200.0, f64::NAN).is_none());
+        assert!(PriorSmoothing::sigmoid(f64::NAN, 0.5).is_none());
+        assert!(PriorSmoothing::sigmoid(200.0, f64::INFINITY).is_none());
+    }
+
+    #[test]
+    fn test_for_corpus_matches_stripped_words() {
+        let table = FrequencyTable::parse("café 3\ncafe 1\nthé 2").unwrap();
+        let corpus = Corpus::new(["cafe", "the"], ["cafe", "the"], true, "");
+        let aligned = table.for_corpus(&corpus);
+        assert_eq!(aligned.count("cafe"), Some(4.0));
+        assert_eq!(
+            aligned.priors(corpus.answers(), PriorSmoothing::Raw),
+            [4.0 / 6.0, 2.0 / 6.0]
+        );
+    }
+
+    #[test]
+    fn test_unknown_words_fall_back_to_uniform() {
+        let table = FrequencyTable::parse(TEXT).unwrap();
+        assert_eq!(
+            table.priors(&["rotor", "abide"], PriorSmoothing::Log),
+            [0.5, 0.5]
+        );
+    }
+}
@@ -0,0 +1,350 @@
+use crate::hint::WordleHint;
+use crate::render::{Palette, RenderStyle, Renderer};
+use crate::share;
+use crate::utils::fnv1a_hash;
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+
+/// How a recorded game ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GameOutcome {
+    Solved,
+    Failed,
+}
+
+/// A finished single-board game: the answer, every guess with the hint it got, and the
+/// outcome. Serializes to JSON as
+/// `{"answer": "cigar", "hints": [{"word": "crane", "hints": "O~XXX"}, ...], ...}`
+#[pyclass(module = "wordle_tournament_lib")]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GameRecord {
+    /// None when the answer was never revealed, e.g. an unsolved adversarial or remote game
+    #[pyo3(get)]
+    answer: Option<String>,
+    #[pyo3(get)]
+    hints: Vec<WordleHint>,
+    outcome: GameOutcome,
+    /// Grading mode the game was played in, e.g. "standard", "adversarial" or "fibble"
+    #[pyo3(get)]
+    grading_mode: String,
+    #[pyo3(get)]
+    hard_mode: bool,
+    /// Fibble games only: the lied-about tile positions (0-based) of each row
+    #[pyo3(get)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    lies: Option<Vec<Vec<usize>>>,
+}
+
+#[pymethods]
+impl GameRecord {
+    #[new]
+    #[pyo3(signature = (answer, hints, solved, grading_mode = "standard".to_string(), hard_mode = false, lies = None))]
+    fn new_game_record(
+        answer: Option<String>,
+        hints: Vec<WordleHint>,
+        solved: bool,
+        grading_mode: String,
+        hard_mode: bool,
+        lies: Option<Vec<Vec<usize>>>,
+    ) -> Self {
+        let outcome = match solved {
+            true => GameOutcome::Solved,
+            false => GameOutcome::Failed,
+        };
+        let record = GameRecord::new(answer, hints, outcome, grading_mode, hard_mode);
+        match lies {
+            Some(lies) => record.with_lies(lies),
+            None => record,
+        }
+    }
+
+    /// 'solved' or 'failed'
+    #[getter]
+    fn outcome(&self) -> &'static str {
+        match self.outcome {
+            GameOutcome::Solved => "solved",
+            GameOutcome::Failed => "failed",
+        }
+    }
+
+    #[getter]
+    pub fn solved(&self) -> bool {
+        self.outcome == GameOutcome::Solved
+    }
+
+    /// Number of guesses made, including the solving guess
+    #[getter]
+    pub fn num_guesses(&self) -> usize {
+        self.hints.len()
+    }
+
+    /// Guessed words, in order
+    #[getter]
+    fn guesses(&self) -> Vec<String> {
+        self.hints
+            .iter()
+            .map(|hint| hint.word().to_string())
+            .collect()
+    }
+
+    #[pyo3(name = "to_json")]
+    fn to_json_py(&self) -> PyResult<String> {
+        serde_json::to_string(self).map_err(to_py_err)
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_json")]
+    fn from_json_py(json: &str) -> PyResult<Self> {
+        serde_json::from_str(json).map_err(to_py_err)
+    }
+
+    /// The whole game drawn in the given style: 'emoji', 'ansi' or 'html'
+    #[pyo3(signature = (style = "emoji", high_contrast = false))]
+    fn render(&self, style: &str, high_contrast: bool) -> PyResult<String> {
+        Ok(Renderer::from_options(style, high_contrast)?.render_game(&self.hints))
+    }
+
+    /// "Wordle 3/20" share text with the emoji grid and no letters
+    #[pyo3(signature = (high_contrast = false))]
+    fn share_text(&self, high_contrast: bool) -> String {
+        share::share_text(self, Palette::new(high_contrast))
+    }
+
+    /// Jupyter shows records as a Wordle board
+    fn _repr_html_(&self) -> String {
+        Renderer::new(RenderStyle::Html, Palette::Standard).render_game(&self.hints)
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self == other
+    }
+
+    /// Equal records hash equally, in every process
+    fn __hash__(&self) -> u64 {
+        self.stable_hash()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "GameRecord(answer={}, guesses={}, outcome='{}', grading_mode='{}', hard_mode={})",
+            match &self.answer {
+                Some(answer) => format!("'{}'", answer),
+                None => "None".to_string(),
+            },
+            self.num_guesses(),
+            self.outcome(),
+            self.grading_mode,
+            match self.hard_mode {
+                true => "True",
+                false => "False",
+            }
+        )
+    }
+}
+
+impl GameRecord {
+    pub fn new(
+        answer: Option<String>,
+        hints: Vec<WordleHint>,
+        outcome: GameOutcome,
+        grading_mode: String,
+        hard_mode: bool,
+    ) -> Self {
+        GameRecord {
+            answer,
+            hints,
+            outcome,
+            grading_mode,
+            hard_mode,
+            lies: None,
+        }
+    }
+
+    /// The same record, noting which tiles of each row the fibble grader lied about
+    pub fn with_lies(self, lies: Vec<Vec<usize>>) -> Self {
+        GameRecord {
+            lies: Some(lies),
+            ..self
+        }
+    }
+
+    /// FNV-1a hash of the record's JSON, which holds every field compared by ==
+    pub fn stable_hash(&self) -> u64 {
+        fnv1a_hash(&serde_json::to_vec(self).expect("Game records always serialize to JSON"))
+    }
+
+    pub fn answer(&self) -> Option<&str> {
+        self.answer.as_deref()
+    }
+
+    pub fn hints(&self) -> &[WordleHint] {
+        &self.hints
+    }
+
+    pub fn is_hard_mode(&self) -> bool {
+        self.hard_mode
+    }
+
+    pub fn outcome_kind(&self) -> GameOutcome {
+        self.outcome
+    }
+}
+
+fn to_py_err(err: serde_json::Error) -> PyErr {
+    PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Invalid game record: {}", err))
+}
+
+fn invalid_data(err: serde_json::Error) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, err)
+}
+
+/// Whether a path should be read and written as JSON Lines (one record per line) rather than
+/// a single JSON array
+pub fn is_jsonl_path<P: AsRef<Path>>(path: P) -> bool {
+    path.as_ref()
+        .extension()
+        .is_some_and(|extension| extension == "jsonl")
+}
+
+/// Write records as one pretty-printed JSON array
+pub fn save_json<P: AsRef<Path>>(path: P, records: &[GameRecord]) -> io::Result<()> {
+    let mut writer = BufWriter::new(fs::File::create(path)?);
+    serde_json::to_writer_pretty(&mut writer, records).map_err(invalid_data)?;
+    writer.flush()
+}
+
+pub fn load_json<P: AsRef<Path>>(path: P) -> io::Result<Vec<GameRecord>> {
+    let reader = BufReader::new(fs::File::open(path)?);
+    serde_json::from_reader(reader).map_err(invalid_data)
+}
+
+/// Write records as JSON Lines, one compact record per line
+pub fn save_jsonl<P: AsRef<Path>>(path: P, records: &[GameRecord]) -> io::Result<()> {
+    let mut writer = BufWriter::new(fs::File::create(path)?);
+    for record in records {
+        serde_json::to_writer(&mut writer, record).map_err(invalid_data)?;
+        writer.write_all(b"\n")?;
+    }
+    writer.flush()
+}
+
+/// Read JSON Lines records. Blank lines are skipped
+pub fn load_jsonl<P: AsRef<Path>>(path: P) -> io::Result<Vec<GameRecord>> {
+    let reader = BufReader::new(fs::File::open(path)?);
+    let mut records = vec![];
+    for line in reader.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        records.push(serde_json::from_str(&line).map_err(invalid_data)?);
+    }
+    Ok(records)
+}
+
+/// Write records as JSON Lines if the path ends in .jsonl, otherwise as a JSON array
+pub fn save_records<P: AsRef<Path>>(path: P, records: &[GameRecord]) -> io::Result<()> {
+    match is_jsonl_path(&path) {
+        true => save_jsonl(path, records),
+        false => save_json(path, records),
+    }
+}
+
+/// Read records written by save_records, picking the format from the path the same way
+pub fn load_records<P: AsRef<Path>>(path: P) -> io::Result<Vec<GameRecord>> {
+    match is_jsonl_path(&path) {
+        true => load_jsonl(path),
+        false => load_json(path),
+    }
+}
+
+/// Save game records to a file: JSON Lines if the path ends in .jsonl, otherwise a JSON array
+#[pyfunction]
+pub fn save_game_records(records: Vec<GameRecord>, path: &str) -> PyResult<()> {
+    Ok(save_records(path, &records)?)
+}
+
+/// Load game records saved by save_game_records (or any tool writing the same format)
+#[pyfunction]
+pub fn load_game_records(path: &str) -> PyResult<Vec<GameRecord>> {
+    Ok(load_records(path)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grade::grade_guess;
+
+    fn sample_records() -> Vec<GameRecord> {
+        vec![
+            GameRecord::new(
+                Some("cigar".to_string()),
+                vec![grade_guess("crane", "cigar"), grade_guess("cigar", "cigar")],
+                GameOutcome::Solved,
+                "standard".to_string(),
+                false,
+            ),
+            GameRecord::new(
+                None,
+                vec![grade_guess("robot", "rotor")],
+                GameOutcome::Failed,
+                "adversarial".to_string(),
+                true,
+            ),
+            GameRecord::new(
+                Some("cigar".to_string()),
+                vec![grade_guess("crane", "cigar")],
+                GameOutcome::Failed,
+                "fibble".to_string(),
+                false,
+            )
+            .with_lies(vec![vec![1]]),
+        ]
+    }
+
+    #[test]
+    fn test_hint_json_format() {
+        let json = serde_json::to_string(&grade_guess("crane", "cigar")).unwrap();
+        assert_eq!(json, r#"{"word":"crane","hints":"O~~XX"}"#);
+        let hint: WordleHint =
+            serde_json::from_str(r#"{"word":"crane","hints":"🟩🟨🟨⬜⬜"}"#).unwrap();
+        assert_eq!(hint, grade_guess("crane", "cigar"));
+        assert!(serde_json::from_str::<WordleHint>(r#"{"word":"crane","hints":"O~X"}"#).is_err());
+    }
+
+    #[test]
+    fn test_json_and_jsonl_round_trip() {
+        let records = sample_records();
+        for extension in ["json", "jsonl"] {
+            let path = std::env::temp_dir().join(format!(
+                "wt_games_test-{}.{}",
+                std::process::id(),
+                extension
+            ));
+            save_records(&path, &records).unwrap();
+            let text = fs::read_to_string(&path).unwrap();
+            let loaded = load_records(&path);
+            fs::remove_file(&path).unwrap();
+
+            assert_eq!(loaded.unwrap(), records);
+            // .jsonl holds one record per line, .json a single array
+            assert_eq!(text.starts_with('['), !is_jsonl_path(&path));
+        }
+    }
+
+    #[test]
+    fn test_equal_records_hash_equally() {
+        let records = sample_records();
+        assert_eq!(records[0].stable_hash(), records[0].clone().stable_hash());
+        assert_ne!(records[0].stable_hash(), records[1].stable_hash());
+        let without_lies = GameRecord {
+            lies: None,
+            ..records[2].clone()
+        };
+        assert_ne!(records[2].stable_hash(), without_lies.stable_hash());
+    }
+}
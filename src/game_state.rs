@@ -0,0 +1,148 @@
+use crate::history::GuessHistory;
+use pyo3::prelude::*;
+
+/// Everything a bot may want to know when asked for a guess, for bots that opt into receiving
+/// it in place of the bare hint history (see GuessStyle)
+#[pyclass(frozen, module = "wordle_tournament_lib")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GameState {
+    /// 1-based number of the guess about to be made
+    #[pyo3(get)]
+    turn: usize,
+    #[pyo3(get)]
+    max_guesses: usize,
+    #[pyo3(get)]
+    hard_mode: bool,
+    /// 0-based board index in multi-board play, None in single-board games
+    #[pyo3(get)]
+    board: Option<usize>,
+    #[pyo3(get)]
+    history: GuessHistory,
+}
+
+#[pymethods]
+impl GameState {
+    /// Guesses left including the one about to be made
+    #[getter]
+    fn guesses_remaining(&self) -> usize {
+        self.max_guesses + 1 - self.turn
+    }
+
+    /// 'normal' or 'hard'
+    #[getter]
+    fn mode(&self) -> &'static str {
+        match self.hard_mode {
+            true => "hard",
+            false => "normal",
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        let board = match self.board {
+            Some(board) => format!(", board={}", board),
+            None => String::new(),
+        };
+        format!(
+            "GameState(turn={}, guesses_remaining={}, mode='{}'{}, history={})",
+            self.turn,
+            self.guesses_remaining(),
+            self.mode(),
+            board,
+            self.history.hints().len()
+        )
+    }
+}
+
+impl GameState {
+    pub fn new(
+        history: GuessHistory,
+        turn: usize,
+        max_guesses: usize,
+        hard_mode: bool,
+        board: Option<usize>,
+    ) -> Self {
+        GameState {
+            turn,
+            max_guesses,
+            hard_mode,
+            board,
+            history,
+        }
+    }
+
+    pub fn history(&self) -> &GuessHistory {
+        &self.history
+    }
+}
+
+/// What a bot's guess method receives: the hint history (the original call style) or a
+/// GameState
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GuessStyle {
+    Hints,
+    State,
+}
+
+impl GuessStyle {
+    /// Call style for one of the bot's guess methods. A `guess_style = "state"` (or "hints")
+    /// class attribute decides; otherwise the method takes a GameState if its first parameter
+    /// is annotated GameState or named `state`
+    pub fn detect(bot: &Bound<'_, PyAny>, method: &str) -> PyResult<Self> {
+        if bot.hasattr("guess_style")? {
+            let style: String = bot.getattr("guess_style")?.extract()?;
+            return match style.as_str() {
+                "hints" => Ok(GuessStyle::Hints),
+                "state" => Ok(GuessStyle::State),
+                _ => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                    "Unknown guess_style '{}' - must be 'hints' or 'state'",
+                    style
+                ))),
+            };
+        }
+
+        let py = bot.py();
+        // Methods without an introspectable signature (like the base class's) take hints
+        let Ok(signature) = py
+            .import("inspect")?
+            .call_method1("signature", (bot.getattr(method)?,))
+        else {
+            return Ok(GuessStyle::Hints);
+        };
+        let parameters = signature.getattr("parameters")?.call_method0("values")?;
+        let Some(first) = parameters.try_iter()?.next().transpose()? else {
+            return Ok(GuessStyle::Hints);
+        };
+
+        let name: String = first.getattr("name")?.extract()?;
+        let annotation = first.getattr("annotation")?;
+        let state_type = py.get_type::<GameState>();
+        // String annotations come from `from __future__ import annotations`
+        let annotated_state = annotation.is(&state_type)
+            || annotation
+                .extract::<String>()
+                .is_ok_and(|annotation| annotation.ends_with("GameState"));
+        match annotated_state || name == "state" {
+            true => Ok(GuessStyle::State),
+            false => Ok(GuessStyle::Hints),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::MAX_GUESSES;
+    use crate::grade::grade_guess;
+
+    #[test]
+    fn test_guesses_remaining_and_mode() {
+        let history = GuessHistory::new(vec![grade_guess("crane", "cigar")]);
+        let state = GameState::new(history, 2, MAX_GUESSES, true, None);
+        assert_eq!(state.guesses_remaining(), MAX_GUESSES - 1);
+        assert_eq!(state.mode(), "hard");
+
+        let first = GameState::new(GuessHistory::default(), 1, MAX_GUESSES, false, Some(3));
+        assert_eq!(first.guesses_remaining(), MAX_GUESSES);
+        assert_eq!(first.mode(), "normal");
+    }
+}
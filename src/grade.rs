@@ -1,19 +1,57 @@
 use crate::common::WORD_LENGTH;
-use crate::hint::{HintType, WordleHint};
+use crate::count_hint::CountHint;
+use crate::hint::{HintType, PatternCode, WordleHint};
+use crate::normalize::{normalize_word, word_length};
 use pyo3::prelude::*;
 
-/// Grades a guess against an answer, returning a WordleHint
+/// A source of hints for a single game. Graders may keep state between guesses
+pub trait Grader {
+    fn grade(&mut self, guess: &str) -> WordleHint;
+}
+
+/// Grades every guess against one answer chosen up front
+pub struct FixedAnswerGrader {
+    answer: String,
+}
+
+impl FixedAnswerGrader {
+    pub fn new(answer: String) -> Self {
+        FixedAnswerGrader { answer }
+    }
+}
+
+impl Grader for FixedAnswerGrader {
+    fn grade(&mut self, guess: &str) -> WordleHint {
+        grade_guess(guess, &self.answer)
+    }
+}
+
+/// Grades a guess against an answer with the same number of letters, returning a
+/// WordleHint. Letters are Unicode scalar values, so both words should be normalized
+/// (see normalize_word). ASCII WORD_LENGTH words take the allocation-free grade_code path.
 pub fn grade_guess(guess: &str, answer: &str) -> WordleHint {
-    assert_eq!(guess.len(), WORD_LENGTH);
-    assert_eq!(answer.len(), WORD_LENGTH);
+    if guess.is_ascii() && answer.is_ascii() {
+        if let (Ok(guess_bytes), Ok(answer_bytes)) = (
+            <&[u8; WORD_LENGTH]>::try_from(guess.as_bytes()),
+            <&[u8; WORD_LENGTH]>::try_from(answer.as_bytes()),
+        ) {
+            return WordleHint::from_pattern_code(
+                guess.to_string(),
+                grade_code(guess_bytes, answer_bytes),
+            );
+        }
+    }
+
+    let num_letters = word_length(guess);
+    assert_eq!(num_letters, word_length(answer));
 
-    let mut hint_arr = [HintType::Absent; WORD_LENGTH];
+    let mut hint_vec = vec![HintType::Absent; num_letters];
     let mut unseen_pool = vec![];
 
     // Mark greens
     for (i, (guess_char, answer_key_char)) in guess.chars().zip(answer.chars()).enumerate() {
         if guess_char == answer_key_char {
-            hint_arr[i] = HintType::Correct;
+            hint_vec[i] = HintType::Correct;
         } else {
             unseen_pool.push(answer_key_char);
         }
@@ -21,31 +59,67 @@ pub fn grade_guess(guess: &str, answer: &str) -> WordleHint {
 
     // Mark yellows
     for (i, guess_char) in guess.chars().enumerate() {
-        if hint_arr[i] == HintType::Absent && unseen_pool.contains(&guess_char) {
+        if hint_vec[i] == HintType::Absent && unseen_pool.contains(&guess_char) {
             unseen_pool.remove(unseen_pool.iter().position(|c| *c == guess_char).unwrap());
-            hint_arr[i] = HintType::Present;
+            hint_vec[i] = HintType::Present;
         }
     }
 
-    WordleHint::new(guess.to_string(), hint_arr)
+    WordleHint::new(guess.to_string(), hint_vec)
+}
+
+/// Grades a guess against an answer with count-only (Jotto/Mastermind) feedback, using
+/// the same duplicate-letter rules as grade_guess
+pub fn grade_counts(guess: &str, answer: &str) -> CountHint {
+    CountHint::from_hint(&grade_guess(guess, answer))
+}
+
+/// Grades a guess against an answer byte-by-byte, returning the packed PatternCode.
+/// Same semantics as grade_guess but without allocating, for use in solver hot loops.
+pub fn grade_code(guess: &[u8; WORD_LENGTH], answer: &[u8; WORD_LENGTH]) -> PatternCode {
+    let mut digits = [HintType::Absent.to_digit(); WORD_LENGTH];
+    let mut unseen_pool = [0u8; WORD_LENGTH];
+    let mut num_unseen = 0;
+
+    // Mark greens
+    for i in 0..WORD_LENGTH {
+        if guess[i] == answer[i] {
+            digits[i] = HintType::Correct.to_digit();
+        } else {
+            unseen_pool[num_unseen] = answer[i];
+            num_unseen += 1;
+        }
+    }
+
+    // Mark yellows
+    for i in 0..WORD_LENGTH {
+        if digits[i] == HintType::Correct.to_digit() {
+            continue;
+        }
+        if let Some(j) = unseen_pool[..num_unseen]
+            .iter()
+            .position(|c| *c == guess[i])
+        {
+            num_unseen -= 1;
+            unseen_pool[j] = unseen_pool[num_unseen];
+            digits[i] = HintType::Present.to_digit();
+        }
+    }
+
+    digits.iter().rev().fold(0, |code, digit| code * 3 + digit)
 }
 
 /// Python wrapper for grade_guess - validates inputs and returns WordleHint
 /// Gonna avoid publicly exposing grade_guess for now since it makes things too easy
 // #[pyfunction(name = "grade_guess")]
 pub fn grade_guess_py(guess: String, answer: String) -> PyResult<WordleHint> {
-    if guess.len() != WORD_LENGTH {
-        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
-            "Guess must be {} letters long, got {}",
-            WORD_LENGTH,
-            guess.len()
-        )));
-    }
-    if answer.len() != WORD_LENGTH {
+    let guess = normalize_word(&guess, false, "");
+    let answer = normalize_word(&answer, false, "");
+    if word_length(&guess) != word_length(&answer) {
         return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
-            "Answer must be {} letters long, got {}",
-            WORD_LENGTH,
-            answer.len()
+            "Guess and answer must be the same length, got {} and {}",
+            word_length(&guess),
+            word_length(&answer)
         )));
     }
     Ok(grade_guess(&guess, &answer))
@@ -102,4 +176,49 @@ mod tests {
         let result = grade_guess("babee", "aback");
         assert_eq!(result.hints(), "~~XXX");
     }
+
+    #[test]
+    fn test_other_word_lengths() {
+        assert_eq!(grade_guess("eerie", "eerie").hints(), "OOOOO");
+        assert_eq!(grade_guess("tool", "loot").hints(), "~OO~");
+        assert_eq!(grade_guess("roster", "robots").hints(), "OO~~XX");
+        assert_eq!(grade_guess("allele", "parley").hints(), "~~X~XX");
+        assert_eq!(grade_guess("letters", "settler").hints(), "~OOO~~~");
+    }
+
+    #[test]
+    fn test_non_ascii_letters() {
+        // Each accented letter is one tile, so hints stay aligned with letters
+        assert_eq!(grade_guess("niños", "señor").hints(), "XXOO~");
+        assert_eq!(grade_guess("äpfel", "apfel").hints(), "XOOOO");
+        assert_eq!(grade_guess("année", "année").hints(), "OOOOO");
+        assert_eq!(grade_guess("öl", "lö").hints(), "~~");
+    }
+
+    #[test]
+    fn test_grade_code_matches_grade_guess() {
+        let pairs = [
+            ("crane", "built"),
+            ("crane", "crane"),
+            ("roost", "robot"),
+            ("allee", "apple"),
+            ("crane", "yummy"),
+            ("ABBEY", "BANAL"),
+            ("array", "alarm"),
+            ("babee", "aback"),
+        ];
+        for (guess, answer) in pairs {
+            let code = grade_code(
+                guess.as_bytes().try_into().unwrap(),
+                answer.as_bytes().try_into().unwrap(),
+            );
+            assert_eq!(
+                code,
+                grade_guess(guess, answer).pattern_code().unwrap(),
+                "Mismatch grading {} against {}",
+                guess,
+                answer
+            );
+        }
+    }
 }
@@ -0,0 +1,182 @@
+use crate::hint::{HintType, WordleHint};
+use std::collections::HashMap;
+use std::fmt;
+
+/// A hard-mode rule broken by a guess, along with the earlier hint that set the rule
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HardModeViolation {
+    /// A revealed green letter was not reused in place
+    MissingGreen {
+        guess: String,
+        letter: char,
+        position: usize,
+        hint_index: usize,
+        hint_word: String,
+    },
+    /// A revealed letter (green or yellow) was left out, or used fewer times than revealed
+    MissingLetter {
+        guess: String,
+        letter: char,
+        count: usize,
+        hint_index: usize,
+        hint_word: String,
+    },
+}
+
+impl fmt::Display for HardModeViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HardModeViolation::MissingGreen {
+                guess,
+                letter,
+                position,
+                hint_index,
+                hint_word,
+            } => write!(
+                f,
+                "Hard mode: guess {} must have '{}' in position {} (revealed green by guess {}, {})",
+                guess,
+                letter.to_ascii_uppercase(),
+                position + 1,
+                hint_index + 1,
+                hint_word
+            ),
+            HardModeViolation::MissingLetter {
+                guess,
+                letter,
+                count,
+                hint_index,
+                hint_word,
+            } => write!(
+                f,
+                "Hard mode: guess {} must contain '{}'{} (revealed by guess {}, {})",
+                guess,
+                letter.to_ascii_uppercase(),
+                match count {
+                    1 => String::new(),
+                    n => format!(" at least {} times", n),
+                },
+                hint_index + 1,
+                hint_word
+            ),
+        }
+    }
+}
+
+/// Check a guess against NYT hard-mode rules: every revealed green must be reused in
+/// place and every revealed letter must appear at least as often as it was revealed.
+/// History is checked in order, so the violation names the earliest hint that set the rule.
+pub fn check_hard_mode(guess: &str, history: &[WordleHint]) -> Result<(), HardModeViolation> {
+    let guess_chars: Vec<char> = guess.chars().collect();
+    let mut guess_counts: HashMap<char, usize> = HashMap::new();
+    for c in &guess_chars {
+        *guess_counts.entry(*c).or_default() += 1;
+    }
+
+    for (hint_index, hint) in history.iter().enumerate() {
+        let revealed: Vec<(char, HintType)> = hint
+            .word()
+            .chars()
+            .zip(hint.hint_types().iter().copied())
+            .collect();
+
+        for (position, (letter, hint_type)) in revealed.iter().enumerate() {
+            if *hint_type == HintType::Correct && guess_chars.get(position) != Some(letter) {
+                return Err(HardModeViolation::MissingGreen {
+                    guess: guess.to_string(),
+                    letter: *letter,
+                    position,
+                    hint_index,
+                    hint_word: hint.word().to_string(),
+                });
+            }
+        }
+
+        let mut revealed_counts: Vec<(char, usize)> = vec![];
+        for (letter, hint_type) in &revealed {
+            if *hint_type == HintType::Absent {
+                continue;
+            }
+            match revealed_counts.iter_mut().find(|(c, _)| c == letter) {
+                Some((_, count)) => *count += 1,
+                None => revealed_counts.push((*letter, 1)),
+            }
+        }
+        for (letter, count) in revealed_counts {
+            if guess_counts.get(&letter).copied().unwrap_or(0) < count {
+                return Err(HardModeViolation::MissingLetter {
+                    guess: guess.to_string(),
+                    letter,
+                    count,
+                    hint_index,
+                    hint_word: hint.word().to_string(),
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grade::grade_guess;
+
+    #[test]
+    fn test_allows_guess_using_all_hints() {
+        let history = vec![grade_guess("crane", "cigar"), grade_guess("cairn", "cigar")];
+        assert_eq!(check_hard_mode("cigar", &history), Ok(()));
+        assert_eq!(check_hard_mode("anything", &[]), Ok(()));
+    }
+
+    #[test]
+    fn test_rejects_moved_green() {
+        let history = vec![grade_guess("crane", "cigar")];
+        let violation = check_hard_mode("acorn", &history).unwrap_err();
+        assert!(matches!(
+            violation,
+            HardModeViolation::MissingGreen {
+                letter: 'c',
+                position: 0,
+                hint_index: 0,
+                ..
+            }
+        ));
+        assert_eq!(
+            violation.to_string(),
+            "Hard mode: guess acorn must have 'C' in position 1 (revealed green by guess 1, crane)"
+        );
+    }
+
+    #[test]
+    fn test_rejects_missing_yellow() {
+        let history = vec![grade_guess("slate", "cigar"), grade_guess("crane", "cigar")];
+        let violation = check_hard_mode("cloud", &history).unwrap_err();
+        assert!(matches!(
+            violation,
+            HardModeViolation::MissingLetter {
+                letter: 'a',
+                count: 1,
+                hint_index: 0,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_counts_duplicate_revealed_letters() {
+        // "array" against "alarm" reveals a green A and a yellow A
+        let history = vec![grade_guess("array", "alarm")];
+        let violation = check_hard_mode("acrid", &history).unwrap_err();
+        assert!(matches!(
+            violation,
+            HardModeViolation::MissingLetter {
+                letter: 'a',
+                count: 2,
+                ..
+            }
+        ));
+        assert_eq!(check_hard_mode("alarm", &history), Ok(()));
+    }
+}
@@ -1,23 +1,43 @@
-use crate::common::WORD_LENGTH;
-use crate::utils::py_print;
+use crate::common::{MAX_WORD_LENGTH, MIN_WORD_LENGTH, WORD_LENGTH};
+use crate::normalize::{normalize_word, word_length};
+use crate::render::{Palette, RenderStyle, Renderer};
+use crate::utils::{fnv1a_hash, py_display_html, py_print};
+use pyo3::basic::CompareOp;
 use pyo3::prelude::*;
+use pyo3::types::{PyIterator, PyList};
+use serde::{Deserialize, Serialize};
+use std::fmt;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Packed base-3 encoding of a hint pattern: one digit per position, where
+/// position `i` contributes `digit * 3^i` (Absent = 0, Present = 1, Correct = 2)
+pub type PatternCode = u8;
+
+/// Number of distinct hint patterns for a word of WORD_LENGTH letters (3^5)
+pub const NUM_PATTERNS: usize = 243;
+
+/// Pattern code of a fully correct guess
+pub const ALL_CORRECT_CODE: PatternCode = (NUM_PATTERNS - 1) as PatternCode;
+
+/// Serialized as its symbol: "O", "~" or "X"
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum HintType {
+    #[serde(rename = "O")]
     Correct,
+    #[serde(rename = "~")]
     Present,
+    #[serde(rename = "X")]
     Absent,
 }
 
 impl HintType {
-    fn from_char(value: char) -> Self {
+    /// Reads a hint character in any HintFormat. Dark-mode (⬛) and high-contrast (🟧/🟦)
+    /// emoji squares are accepted too
+    pub fn from_char(value: char) -> Option<Self> {
         match value {
-            'O' => HintType::Correct,
-            '~' => HintType::Present,
-            'X' => HintType::Absent,
-            _ => {
-                unreachable!("Invalid hint type: '{}'. Must be 'O' (correct), '~' (misplaced), or 'X' (absent)", value)
-            }
+            'O' | 'G' | 'g' | '2' | '🟩' | '🟧' => Some(HintType::Correct),
+            '~' | 'Y' | 'y' | '1' | '🟨' | '🟦' => Some(HintType::Present),
+            'X' | 'B' | 'b' | '0' | '⬜' | '⬛' => Some(HintType::Absent),
+            _ => None,
         }
     }
 
@@ -28,41 +48,269 @@ impl HintType {
             HintType::Absent => 'X',
         }
     }
+
+    /// Base-3 digit used for this hint in a PatternCode
+    pub fn to_digit(self) -> u8 {
+        match self {
+            HintType::Absent => 0,
+            HintType::Present => 1,
+            HintType::Correct => 2,
+        }
+    }
+
+    fn from_digit(digit: u8) -> Self {
+        match digit {
+            0 => HintType::Absent,
+            1 => HintType::Present,
+            _ => HintType::Correct,
+        }
+    }
+}
+
+/// The text encodings a hint pattern can be written in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HintFormat {
+    /// O / ~ / X, the tournament's own format
+    Symbols,
+    /// 🟩 / 🟨 / ⬜, as in NYT share text
+    Emoji,
+    /// G / Y / B (green, yellow, black)
+    Letters,
+    /// 2 / 1 / 0, the digits of a PatternCode
+    Digits,
+}
+
+impl HintFormat {
+    pub const ALL: [HintFormat; 4] = [
+        HintFormat::Symbols,
+        HintFormat::Emoji,
+        HintFormat::Letters,
+        HintFormat::Digits,
+    ];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "symbols" => Some(HintFormat::Symbols),
+            "emoji" => Some(HintFormat::Emoji),
+            "letters" => Some(HintFormat::Letters),
+            "digits" => Some(HintFormat::Digits),
+            _ => None,
+        }
+    }
+
+    pub fn char_for(self, hint: HintType) -> char {
+        match (self, hint) {
+            (HintFormat::Symbols, _) => hint.to_char(),
+            (HintFormat::Emoji, HintType::Correct) => '🟩',
+            (HintFormat::Emoji, HintType::Present) => '🟨',
+            (HintFormat::Emoji, HintType::Absent) => '⬜',
+            (HintFormat::Letters, HintType::Correct) => 'G',
+            (HintFormat::Letters, HintType::Present) => 'Y',
+            (HintFormat::Letters, HintType::Absent) => 'B',
+            (HintFormat::Digits, _) => (b'0' + hint.to_digit()) as char,
+        }
+    }
+
+    pub fn format(self, hints: &[HintType]) -> String {
+        hints.iter().map(|hint| self.char_for(*hint)).collect()
+    }
+}
+
+/// A character in a hint string that is not a hint in any HintFormat
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HintParseError {
+    pub character: char,
+    /// 1-based position among the hint's characters, ignoring whitespace
+    pub position: usize,
+}
+
+impl fmt::Display for HintParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Invalid hint character '{}' at position {}. Use O/~/X, G/Y/B, 2/1/0 or 🟩/🟨/⬜",
+            self.character, self.position
+        )
+    }
+}
+
+impl From<HintParseError> for PyErr {
+    fn from(err: HintParseError) -> PyErr {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(err.to_string())
+    }
+}
+
+/// Why a raw word and hint string could not be put back together as a WordleHint
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RawHintError {
+    Parse(HintParseError),
+    /// The word and the hint string have different numbers of letters
+    LengthMismatch,
+}
+
+impl fmt::Display for RawHintError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RawHintError::Parse(err) => err.fmt(f),
+            RawHintError::LengthMismatch => write!(f, "Word and hints must have the same length"),
+        }
+    }
+}
+
+impl From<HintParseError> for RawHintError {
+    fn from(err: HintParseError) -> RawHintError {
+        RawHintError::Parse(err)
+    }
 }
 
-#[pyclass]
-#[derive(Clone)]
+impl From<RawHintError> for PyErr {
+    fn from(err: RawHintError) -> PyErr {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(err.to_string())
+    }
+}
+
+/// Parses a hint string written in any HintFormat (formats may even be mixed). Whitespace and
+/// emoji variation selectors are skipped, so spaced-out share text like "🟩 🟨 ⬜" parses
+pub fn parse_hints(hints: &str) -> Result<Vec<HintType>, HintParseError> {
+    hints
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '\u{FE0F}')
+        .enumerate()
+        .map(|(i, c)| {
+            HintType::from_char(c).ok_or(HintParseError {
+                character: c,
+                position: i + 1,
+            })
+        })
+        .collect()
+}
+
+/// Packs a hint array into its PatternCode
+pub fn encode_pattern(hints: &[HintType; WORD_LENGTH]) -> PatternCode {
+    hints
+        .iter()
+        .rev()
+        .fold(0, |code, hint| code * 3 + hint.to_digit())
+}
+
+/// Unpacks a PatternCode into a hint array. Panics if code >= NUM_PATTERNS
+pub fn decode_pattern(code: PatternCode) -> [HintType; WORD_LENGTH] {
+    assert!(
+        (code as usize) < NUM_PATTERNS,
+        "Pattern code {} out of range",
+        code
+    );
+    let mut hints = [HintType::Absent; WORD_LENGTH];
+    let mut rest = code;
+    for hint in hints.iter_mut() {
+        *hint = HintType::from_digit(rest % 3);
+        rest /= 3;
+    }
+    hints
+}
+
+/// A guess and its hint pattern. Hints are values: two hints are equal when their word and
+/// pattern are, and they order by word, then pattern. Serialized as
+/// `{"word": "crane", "hints": "O~XXX"}`; any HintFormat is accepted when deserializing
+#[pyclass(module = "wordle_tournament_lib")]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(into = "SerializedHint", try_from = "SerializedHint")]
 pub struct WordleHint {
     #[pyo3(get)]
     word: String,
-    hints: [HintType; WORD_LENGTH],
+    hints: Vec<HintType>,
+}
+
+/// On-disk form of a WordleHint, with the pattern as a string
+#[derive(Serialize, Deserialize)]
+struct SerializedHint {
+    word: String,
+    hints: String,
+}
+
+impl From<WordleHint> for SerializedHint {
+    fn from(hint: WordleHint) -> Self {
+        SerializedHint {
+            hints: hint.hints(),
+            word: hint.word,
+        }
+    }
+}
+
+impl TryFrom<SerializedHint> for WordleHint {
+    type Error = String;
+
+    fn try_from(serialized: SerializedHint) -> Result<Self, Self::Error> {
+        let word = normalize_word(&serialized.word, false, "");
+        let hints = parse_hints(&serialized.hints).map_err(|e| e.to_string())?;
+        if word_length(&word) != hints.len() {
+            return Err(format!(
+                "Word {} and hints {} have different lengths",
+                word, serialized.hints
+            ));
+        }
+        Ok(WordleHint::new(word, hints))
+    }
 }
 
 #[pymethods]
 impl WordleHint {
     #[new]
     pub fn new_hint(word: String, hints: String) -> PyResult<Self> {
-        if word.len() != WORD_LENGTH {
+        let word = normalize_word(&word, false, "");
+        let num_letters = word_length(&word);
+        if !(MIN_WORD_LENGTH..=MAX_WORD_LENGTH).contains(&num_letters) {
             return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
-                "Word must be {} letters long",
-                WORD_LENGTH
+                "Word must be between {} and {} letters long",
+                MIN_WORD_LENGTH, MAX_WORD_LENGTH
             )));
         }
-        if word.len() != hints.len() {
+
+        let hint_vec = parse_hints(&hints)?;
+        if num_letters != hint_vec.len() {
             return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
                 "Word and hints must have the same length",
             ));
         }
+        Ok(WordleHint::new(word, hint_vec))
+    }
 
-        let hint_vec: Vec<HintType> = hints.chars().map(HintType::from_char).collect();
-        let hint_array: [HintType; WORD_LENGTH] = hint_vec.try_into().map_err(|_| {
-            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
-                "Expected exactly {} hints",
+    /// Build a hint from its packed PatternCode (5-letter words only)
+    #[staticmethod]
+    #[pyo3(name = "from_pattern_code")]
+    fn from_pattern_code_py(word: String, code: usize) -> PyResult<Self> {
+        let word = normalize_word(&word, false, "");
+        if word_length(&word) != WORD_LENGTH {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Pattern codes only exist for {}-letter words",
                 WORD_LENGTH
+            )));
+        }
+        if code >= NUM_PATTERNS {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Pattern code must be below {}, got {}",
+                NUM_PATTERNS, code
+            )));
+        }
+        Ok(WordleHint::from_pattern_code(word, code as PatternCode))
+    }
+
+    /// The hint pattern in the given format: 'symbols' (O~X), 'emoji', 'letters' (GYB)
+    /// or 'digits' (210)
+    #[pyo3(signature = (format = "symbols"))]
+    fn format_hints(&self, format: &str) -> PyResult<String> {
+        let format = HintFormat::from_name(format).ok_or_else(|| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Unknown hint format '{}' - must be 'symbols', 'emoji', 'letters' or 'digits'",
+                format
             ))
         })?;
+        Ok(format.format(&self.hints))
+    }
 
-        Ok(WordleHint::new(word, hint_array))
+    #[getter(pattern_code)]
+    fn pattern_code_py(&self) -> Option<PatternCode> {
+        self.pattern_code()
     }
 
     #[getter]
@@ -75,26 +323,69 @@ impl WordleHint {
 
     #[getter]
     pub fn hints(&self) -> String {
-        self.hints.iter().map(|h| h.to_char()).collect()
+        HintFormat::Symbols.format(&self.hints)
     }
 
-    pub fn visualize_hint(&self, py: Python) -> PyResult<()> {
-        let mut letters = Vec::new();
-        let mut squares = Vec::new();
-
-        for (letter, hint_type) in self.word.chars().zip(self.hints.iter()) {
-            letters.push(letter.to_uppercase().to_string());
-            match hint_type {
-                HintType::Correct => squares.push("🟩"),
-                HintType::Present => squares.push("🟨"),
-                HintType::Absent => squares.push("⬜"),
-            }
+    /// Print the hint in the given style: 'emoji' (letters over emoji squares), 'ansi'
+    /// (colored terminal tiles) or 'html'
+    #[pyo3(signature = (style = "emoji", high_contrast = false))]
+    pub fn visualize_hint(&self, py: Python, style: &str, high_contrast: bool) -> PyResult<()> {
+        let renderer = Renderer::from_options(style, high_contrast)?;
+        match renderer.style() {
+            RenderStyle::Html => py_display_html(py, &renderer.render_hint(self)),
+            _ => py_print(py, &renderer.render_hint(self)),
         }
+    }
+
+    /// The hint drawn in the given style, as a string
+    #[pyo3(signature = (style = "emoji", high_contrast = false))]
+    fn render(&self, style: &str, high_contrast: bool) -> PyResult<String> {
+        Ok(Renderer::from_options(style, high_contrast)?.render_hint(self))
+    }
+
+    /// Jupyter shows hints as a row of Wordle tiles
+    fn _repr_html_(&self) -> String {
+        Renderer::new(RenderStyle::Html, Palette::Standard).render_hint(self)
+    }
+
+    fn __richcmp__(&self, other: &Self, op: CompareOp) -> bool {
+        op.matches(self.cmp(other))
+    }
+
+    /// Stable across processes, unlike Python's salted str hashes
+    fn __hash__(&self) -> u64 {
+        self.stable_hash()
+    }
+
+    fn __len__(&self) -> usize {
+        self.hints.len()
+    }
+
+    /// Iterate over (letter, hint) pairs, like word_hint_pairs
+    fn __iter__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyIterator>> {
+        PyList::new(py, self.word_hint_pairs())?.try_iter()
+    }
+
+    fn __str__(&self) -> String {
+        format!("{} {}", self.word, self.hints())
+    }
+
+    /// Pickle as a restore_hint call, so hints can be sent to multiprocessing workers. Unlike
+    /// the constructor it takes the word as is, so the harness's dummy-guess hints round-trip
+    fn __reduce__(&self, py: Python) -> PyResult<(Py<PyAny>, (String, String))> {
+        let restore = py
+            .import("wordle_tournament_lib")?
+            .getattr("_restore_hint")?
+            .unbind();
+        Ok((restore, (self.word.clone(), self.hints())))
+    }
 
-        let output = format!("{}\n{}", letters.join(" "), squares.join(" "));
-        py_print(py, &output)?;
+    fn __copy__(&self) -> Self {
+        self.clone()
+    }
 
-        Ok(())
+    fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> Self {
+        self.clone()
     }
 
     fn __repr__(&self) -> String {
@@ -112,15 +403,188 @@ impl WordleHint {
 }
 
 impl WordleHint {
-    pub fn new(word: String, hints: [HintType; WORD_LENGTH]) -> Self {
+    pub fn new(word: String, hints: Vec<HintType>) -> Self {
         WordleHint { word, hints }
     }
 
+    pub fn from_pattern_code(word: String, code: PatternCode) -> Self {
+        WordleHint::new(word, decode_pattern(code).to_vec())
+    }
+
+    /// Hint from a word taken as is and a hint string in any HintFormat. Unlike new_hint the
+    /// word is not normalized and may fall outside the word length range, but it must still
+    /// have one letter per hint
+    pub fn from_raw(word: String, hints: &str) -> Result<Self, RawHintError> {
+        let hints = parse_hints(hints)?;
+        if word_length(&word) != hints.len() {
+            return Err(RawHintError::LengthMismatch);
+        }
+        Ok(WordleHint::new(word, hints))
+    }
+
     pub fn new_all_correct(word: String) -> Self {
-        WordleHint::new(word, [HintType::Correct; WORD_LENGTH])
+        let num_letters = word_length(&word);
+        WordleHint::new(word, vec![HintType::Correct; num_letters])
     }
 
     pub fn is_fully_correct(&self) -> bool {
         self.hints.iter().all(|h| *h == HintType::Correct)
     }
+
+    /// Uppercase letters separated by spaces, e.g. "H E L L O"
+    pub fn spaced_letters(&self) -> String {
+        let letters: Vec<String> = self
+            .word
+            .chars()
+            .map(|letter| letter.to_uppercase().to_string())
+            .collect();
+        letters.join(" ")
+    }
+
+    /// Emoji squares separated by spaces, e.g. "🟩 🟨 ⬜ ⬜ 🟨"
+    pub fn emoji_squares(&self) -> String {
+        let squares: Vec<&str> = self
+            .hints
+            .iter()
+            .map(|hint_type| match hint_type {
+                HintType::Correct => "🟩",
+                HintType::Present => "🟨",
+                HintType::Absent => "⬜",
+            })
+            .collect();
+        squares.join(" ")
+    }
+
+    pub fn word(&self) -> &str {
+        &self.word
+    }
+
+    pub fn hint_types(&self) -> &[HintType] {
+        &self.hints
+    }
+
+    pub fn word_length(&self) -> usize {
+        self.hints.len()
+    }
+
+    /// FNV-1a hash of the word and pattern, the same in every process and library version
+    pub fn stable_hash(&self) -> u64 {
+        fnv1a_hash(format!("{}\0{}", self.word, self.hints()).as_bytes())
+    }
+
+    /// Packed PatternCode of this hint, or None if it is not a WORD_LENGTH hint
+    pub fn pattern_code(&self) -> Option<PatternCode> {
+        let hints: &[HintType; WORD_LENGTH] = self.hints.as_slice().try_into().ok()?;
+        Some(encode_pattern(hints))
+    }
+}
+
+/// Rebuild a pickled WordleHint from its raw word and hint string, skipping new_hint's
+/// normalization and word length range check
+#[pyfunction(name = "_restore_hint")]
+pub fn restore_hint(word: String, hints: &str) -> PyResult<WordleHint> {
+    Ok(WordleHint::from_raw(word, hints)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_raw_only_matches_letter_counts() {
+        let dummy = WordleHint::new_all_correct(crate::common::DUMMY_GUESS.to_string());
+        let restored = WordleHint::from_raw(dummy.word().to_string(), &dummy.hints()).unwrap();
+        assert_eq!(restored, dummy);
+
+        assert_eq!(
+            WordleHint::from_raw("crane".to_string(), "OO"),
+            Err(RawHintError::LengthMismatch)
+        );
+        assert!(matches!(
+            WordleHint::from_raw("crane".to_string(), "OOOOZ"),
+            Err(RawHintError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn test_pattern_code_round_trip() {
+        for code in 0..NUM_PATTERNS as PatternCode {
+            assert_eq!(encode_pattern(&decode_pattern(code)), code);
+        }
+    }
+
+    #[test]
+    fn test_pattern_code_digits() {
+        assert_eq!(encode_pattern(&[HintType::Absent; WORD_LENGTH]), 0);
+        assert_eq!(
+            encode_pattern(&[HintType::Correct; WORD_LENGTH]),
+            ALL_CORRECT_CODE
+        );
+        let hint = WordleHint::new(
+            "hello".to_string(),
+            vec![
+                HintType::Correct,
+                HintType::Present,
+                HintType::Absent,
+                HintType::Absent,
+                HintType::Present,
+            ],
+        );
+        // 2*1 + 1*3 + 0*9 + 0*27 + 1*81
+        assert_eq!(hint.pattern_code(), Some(86));
+        let decoded = WordleHint::from_pattern_code("hello".to_string(), 86);
+        assert_eq!(decoded.hints(), "O~XX~");
+    }
+
+    #[test]
+    fn test_formats_round_trip() {
+        for code in 0..NUM_PATTERNS as PatternCode {
+            let hints = decode_pattern(code);
+            for format in HintFormat::ALL {
+                assert_eq!(parse_hints(&format.format(&hints)).unwrap(), hints);
+            }
+        }
+        assert_eq!(HintFormat::Emoji.format(&decode_pattern(86)), "🟩🟨⬜⬜🟨");
+        assert_eq!(HintFormat::Letters.format(&decode_pattern(86)), "GYBBY");
+        assert_eq!(HintFormat::Digits.format(&decode_pattern(86)), "21001");
+    }
+
+    #[test]
+    fn test_parse_accepts_spaced_emoji() {
+        let parsed = parse_hints("🟩 🟨 ⬛ ⬜\u{FE0F} 🟨").unwrap();
+        assert_eq!(parsed, decode_pattern(86));
+    }
+
+    #[test]
+    fn test_parse_error_names_character_and_position() {
+        let err = parse_hints("OO~Q X").unwrap_err();
+        assert_eq!(
+            err,
+            HintParseError {
+                character: 'Q',
+                position: 4
+            }
+        );
+        assert!(err.to_string().contains("'Q' at position 4"));
+    }
+
+    #[test]
+    fn test_value_semantics() {
+        let hint = WordleHint::from_pattern_code("crane".to_string(), 86);
+        let same = WordleHint::new("crane".to_string(), parse_hints("O~XX~").unwrap());
+        assert_eq!(hint, same);
+        assert_eq!(hint.stable_hash(), same.stable_hash());
+
+        let other = WordleHint::from_pattern_code("crane".to_string(), 85);
+        assert_ne!(hint, other);
+        assert_ne!(hint.stable_hash(), other.stable_hash());
+        assert!(WordleHint::new_all_correct("cigar".to_string()) < hint);
+    }
+
+    #[test]
+    fn test_pattern_code_only_for_standard_length() {
+        let hint = WordleHint::new_all_correct("planet".to_string());
+        assert_eq!(hint.word_length(), 6);
+        assert_eq!(hint.pattern_code(), None);
+    }
 }
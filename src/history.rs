@@ -0,0 +1,201 @@
+use crate::constraints::{Constraints, HintLengthMismatch};
+use crate::hint::WordleHint;
+use crate::utils::fnv1a_hash;
+use pyo3::basic::CompareOp;
+use pyo3::prelude::*;
+use pyo3::types::{PyIterator, PyList, PySlice};
+
+/// The hints a bot has received so far in one game, oldest first. Passed to guess() in place
+/// of a list: it reads like a list (len, indexing, slicing, iteration) but cannot be changed,
+/// so a bot can't corrupt the harness's copy
+#[pyclass(frozen, module = "wordle_tournament_lib")]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct GuessHistory {
+    hints: Vec<WordleHint>,
+}
+
+#[pymethods]
+impl GuessHistory {
+    #[new]
+    #[pyo3(signature = (hints = vec![]))]
+    fn new_guess_history(hints: Vec<WordleHint>) -> Self {
+        GuessHistory::new(hints)
+    }
+
+    fn __len__(&self) -> usize {
+        self.hints.len()
+    }
+
+    /// A single hint for an integer index (negative counts from the end), or a GuessHistory
+    /// for a slice
+    fn __getitem__(&self, py: Python, index: &Bound<'_, PyAny>) -> PyResult<Py<PyAny>> {
+        if let Ok(slice) = index.downcast::<PySlice>() {
+            let indices = slice.indices(self.hints.len() as isize)?;
+            let hints = (0..indices.slicelength)
+                .map(|i| self.hints[(indices.start + i as isize * indices.step) as usize].clone())
+                .collect();
+            return Ok(Py::new(py, GuessHistory::new(hints))?.into_any());
+        }
+
+        let position: isize = index.extract()?;
+        let resolved = match position < 0 {
+            true => position + self.hints.len() as isize,
+            false => position,
+        };
+        match usize::try_from(resolved)
+            .ok()
+            .and_then(|i| self.hints.get(i))
+        {
+            Some(hint) => Ok(Py::new(py, hint.clone())?.into_any()),
+            None => Err(PyErr::new::<pyo3::exceptions::PyIndexError, _>(format!(
+                "Guess history index {} out of range for {} hints",
+                position,
+                self.hints.len()
+            ))),
+        }
+    }
+
+    fn __iter__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyIterator>> {
+        PyList::new(py, self.hints.clone())?.try_iter()
+    }
+
+    /// Most recent hint, or None before the first guess
+    #[pyo3(name = "last")]
+    fn last_py(&self) -> Option<WordleHint> {
+        self.last().cloned()
+    }
+
+    /// 1-based number of the guess about to be made
+    #[getter]
+    fn turn(&self) -> usize {
+        self.hints.len() + 1
+    }
+
+    /// Guessed words, in order
+    fn guessed_words(&self) -> Vec<String> {
+        self.hints
+            .iter()
+            .map(|hint| hint.word().to_string())
+            .collect()
+    }
+
+    /// Everything the hints so far reveal about the answer
+    #[pyo3(name = "constraints")]
+    fn constraints_py(&self) -> PyResult<Constraints> {
+        Ok(self.constraints()?)
+    }
+
+    /// The hints as a new (mutable) list
+    fn to_list(&self) -> Vec<WordleHint> {
+        self.hints.clone()
+    }
+
+    /// Equal to another GuessHistory or a list of the same hints
+    fn __richcmp__(&self, other: &Bound<'_, PyAny>, op: CompareOp) -> PyResult<Py<PyAny>> {
+        let py = other.py();
+        let other_hints = match other.downcast::<GuessHistory>() {
+            Ok(history) => Some(history.get().hints.clone()),
+            Err(_) => match other.downcast::<PyList>() {
+                Ok(list) => list.extract::<Vec<WordleHint>>().ok(),
+                Err(_) => None,
+            },
+        };
+        let equal = match other_hints {
+            Some(hints) => hints == self.hints,
+            None => return Ok(py.NotImplemented()),
+        };
+        match op {
+            CompareOp::Eq => Ok(equal.into_pyobject(py)?.to_owned().into_any().unbind()),
+            CompareOp::Ne => Ok((!equal).into_pyobject(py)?.to_owned().into_any().unbind()),
+            _ => Ok(py.NotImplemented()),
+        }
+    }
+
+    /// Same value across runs and processes, so it can key an on-disk cache
+    fn __hash__(&self) -> u64 {
+        self.stable_hash()
+    }
+
+    fn __repr__(&self) -> String {
+        let hints: Vec<String> = self
+            .hints
+            .iter()
+            .map(|hint| format!("{} {}", hint.word(), hint.hints()))
+            .collect();
+        format!("GuessHistory([{}])", hints.join(", "))
+    }
+}
+
+impl GuessHistory {
+    pub fn new(hints: Vec<WordleHint>) -> Self {
+        GuessHistory { hints }
+    }
+
+    pub fn hints(&self) -> &[WordleHint] {
+        &self.hints
+    }
+
+    pub fn last(&self) -> Option<&WordleHint> {
+        self.hints.last()
+    }
+
+    pub fn is_solved(&self) -> bool {
+        self.last().is_some_and(|hint| hint.is_fully_correct())
+    }
+
+    pub fn push(&mut self, hint: WordleHint) {
+        self.hints.push(hint);
+    }
+
+    /// Fails if the hints have different lengths, like Constraints::from_hints
+    pub fn constraints(&self) -> Result<Constraints, HintLengthMismatch> {
+        Constraints::from_hints(&self.hints)
+    }
+
+    /// FNV-1a over each hint's stable hash, in order
+    pub fn stable_hash(&self) -> u64 {
+        let bytes: Vec<u8> = self
+            .hints
+            .iter()
+            .flat_map(|hint| hint.stable_hash().to_le_bytes())
+            .collect();
+        fnv1a_hash(&bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grade::grade_guess;
+
+    #[test]
+    fn test_history_helpers() {
+        let mut history = GuessHistory::default();
+        assert_eq!(history.turn(), 1);
+        assert!(history.last().is_none());
+        assert!(!history.is_solved());
+
+        history.push(grade_guess("crane", "cigar"));
+        history.push(grade_guess("cigar", "cigar"));
+        assert_eq!(history.turn(), 3);
+        assert_eq!(history.guessed_words(), ["crane", "cigar"]);
+        assert!(history.is_solved());
+        assert!(history.constraints().unwrap().allows("cigar"));
+        assert!(!history.constraints().unwrap().allows("crane"));
+    }
+
+    #[test]
+    fn test_stable_hash_depends_on_order() {
+        let first = grade_guess("crane", "cigar");
+        let second = grade_guess("robot", "cigar");
+        let history = GuessHistory::new(vec![first.clone(), second.clone()]);
+        assert_eq!(
+            history.stable_hash(),
+            GuessHistory::new(vec![first.clone(), second.clone()]).stable_hash()
+        );
+        assert_ne!(
+            history.stable_hash(),
+            GuessHistory::new(vec![second, first]).stable_hash()
+        );
+    }
+}
@@ -0,0 +1,205 @@
+use crate::constraints::{Constraints, HintLengthMismatch};
+use crate::hint::{HintType, WordleHint};
+use crate::normalize::normalize_word;
+use crate::render::{Palette, RenderStyle, Renderer};
+use pyo3::prelude::*;
+use std::collections::BTreeMap;
+
+/// QWERTY rows of the on-screen keyboard. Letters outside them (e.g. from accented word
+/// lists) get a row of their own
+const KEYBOARD_ROWS: [&str; 3] = ["qwertyuiop", "asdfghjkl", "zxcvbnm"];
+
+/// Per-letter summary of a hint list, like the colors of the NYT on-screen keyboard: each
+/// letter takes the best hint it has received anywhere (correct over present over absent).
+/// Known positions and count bounds come from the hints' Constraints
+#[pyclass(frozen, module = "wordle_tournament_lib")]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct KeyboardState {
+    statuses: BTreeMap<char, HintType>,
+    constraints: Constraints,
+}
+
+#[pymethods]
+impl KeyboardState {
+    #[new]
+    #[pyo3(signature = (hints = vec![]))]
+    fn new_keyboard_state(hints: Vec<WordleHint>) -> PyResult<Self> {
+        Ok(KeyboardState::from_hints(&hints)?)
+    }
+
+    /// Best hint each guessed letter has received, as a symbol ('O', '~' or 'X')
+    #[getter(statuses)]
+    fn statuses_py(&self) -> BTreeMap<char, char> {
+        self.statuses
+            .iter()
+            .map(|(letter, status)| (*letter, status.to_char()))
+            .collect()
+    }
+
+    /// Best hint a letter has received as a symbol, or None if it hasn't been guessed
+    #[pyo3(name = "status")]
+    fn status_py(&self, letter: &str) -> PyResult<Option<char>> {
+        Ok(self.status(single_letter(letter)?).map(HintType::to_char))
+    }
+
+    /// 0-based positions where the letter is known to be
+    #[pyo3(name = "known_positions")]
+    fn known_positions_py(&self, letter: &str) -> PyResult<Vec<usize>> {
+        Ok(self.known_positions(single_letter(letter)?))
+    }
+
+    /// 0-based positions the letter is known not to occupy
+    #[pyo3(name = "excluded_positions")]
+    fn excluded_positions_py(&self, letter: &str) -> PyResult<Vec<usize>> {
+        Ok(self.excluded_positions(single_letter(letter)?))
+    }
+
+    /// (minimum, maximum) number of times the letter appears in the answer. The maximum is
+    /// None until a gray has capped it
+    #[pyo3(name = "count_bounds")]
+    fn count_bounds_py(&self, letter: &str) -> PyResult<(usize, Option<usize>)> {
+        Ok(self.count_bounds(single_letter(letter)?))
+    }
+
+    /// The keyboard drawn in the given style: 'emoji', 'ansi' or 'html'
+    #[pyo3(signature = (style = "emoji", high_contrast = false))]
+    fn render(&self, style: &str, high_contrast: bool) -> PyResult<String> {
+        Ok(Renderer::from_options(style, high_contrast)?.render_keyboard(self))
+    }
+
+    /// Jupyter shows keyboard states as a keyboard
+    fn _repr_html_(&self) -> String {
+        Renderer::new(RenderStyle::Html, Palette::Standard).render_keyboard(self)
+    }
+
+    fn __repr__(&self) -> String {
+        let letters = |hint_type: HintType| -> String {
+            self.statuses
+                .iter()
+                .filter(|(_, status)| **status == hint_type)
+                .map(|(letter, _)| *letter)
+                .collect()
+        };
+        format!(
+            "KeyboardState(correct='{}', present='{}', absent='{}')",
+            letters(HintType::Correct),
+            letters(HintType::Present),
+            letters(HintType::Absent)
+        )
+    }
+}
+
+impl KeyboardState {
+    /// Fails if the hints have different lengths, like Constraints::from_hints
+    pub fn from_hints(hints: &[WordleHint]) -> Result<Self, HintLengthMismatch> {
+        let constraints = Constraints::from_hints(hints)?;
+        let mut statuses: BTreeMap<char, HintType> = BTreeMap::new();
+        for hint in hints {
+            for (letter, hint_type) in hint.word().chars().zip(hint.hint_types()) {
+                let status = statuses.entry(letter).or_insert(*hint_type);
+                // HintType orders Correct < Present < Absent, so the best hint is the smallest
+                *status = (*status).min(*hint_type);
+            }
+        }
+        Ok(KeyboardState {
+            statuses,
+            constraints,
+        })
+    }
+
+    pub fn status(&self, letter: char) -> Option<HintType> {
+        self.statuses.get(&letter).copied()
+    }
+
+    pub fn known_positions(&self, letter: char) -> Vec<usize> {
+        (0..self.constraints.word_length().unwrap_or(0))
+            .filter(|position| self.constraints.green_at(*position) == Some(letter))
+            .collect()
+    }
+
+    pub fn excluded_positions(&self, letter: char) -> Vec<usize> {
+        (0..self.constraints.word_length().unwrap_or(0))
+            .filter(|position| self.constraints.is_excluded(letter, *position))
+            .collect()
+    }
+
+    pub fn count_bounds(&self, letter: char) -> (usize, Option<usize>) {
+        (
+            self.constraints.min_count(letter),
+            self.constraints.max_count(letter),
+        )
+    }
+
+    /// Keyboard rows with each key's status: QWERTY, plus a row of any other guessed letters
+    pub fn rows(&self) -> Vec<Vec<(char, Option<HintType>)>> {
+        let mut rows: Vec<Vec<(char, Option<HintType>)>> = KEYBOARD_ROWS
+            .iter()
+            .map(|row| row.chars().map(|key| (key, self.status(key))).collect())
+            .collect();
+        let extra: Vec<(char, Option<HintType>)> = self
+            .statuses
+            .iter()
+            .filter(|(letter, _)| !KEYBOARD_ROWS.iter().any(|row| row.contains(**letter)))
+            .map(|(letter, status)| (*letter, Some(*status)))
+            .collect();
+        if !extra.is_empty() {
+            rows.push(extra);
+        }
+        rows
+    }
+}
+
+fn single_letter(letter: &str) -> PyResult<char> {
+    let letter = normalize_word(letter, false, "");
+    let mut chars = letter.chars();
+    match (chars.next(), chars.next()) {
+        (Some(letter), None) => Ok(letter),
+        _ => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+            "Expected a single letter, got '{}'",
+            letter
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grade::grade_guess;
+
+    #[test]
+    fn test_best_status_wins() {
+        // geese grays two of its e's, but crane already showed e green at the end
+        let keyboard = KeyboardState::from_hints(&[
+            grade_guess("crane", "abide"),
+            grade_guess("geese", "abide"),
+        ])
+        .unwrap();
+        assert_eq!(keyboard.status('e'), Some(HintType::Correct));
+        assert_eq!(keyboard.status('a'), Some(HintType::Present));
+        assert_eq!(keyboard.status('c'), Some(HintType::Absent));
+        assert_eq!(keyboard.status('z'), None);
+        assert_eq!(keyboard.known_positions('e'), [4]);
+        assert_eq!(keyboard.count_bounds('e'), (1, Some(1)));
+        assert_eq!(keyboard.count_bounds('a'), (1, None));
+        assert_eq!(keyboard.excluded_positions('a'), [2]);
+    }
+
+    #[test]
+    fn test_rows_add_non_qwerty_letters() {
+        let keyboard = KeyboardState::from_hints(&[grade_guess("crane", "cigar")]).unwrap();
+        let rows = keyboard.rows();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0][3], ('r', Some(HintType::Present)));
+
+        let keyboard = KeyboardState::from_hints(&[grade_guess("ñandú", "ñandú")]).unwrap();
+        let rows = keyboard.rows();
+        assert_eq!(rows.len(), 4);
+        assert_eq!(
+            rows[3],
+            [
+                ('ñ', Some(HintType::Correct)),
+                ('ú', Some(HintType::Correct))
+            ]
+        );
+    }
+}
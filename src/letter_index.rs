@@ -0,0 +1,460 @@
+use crate::constraints::{Constraints, HintLengthMismatch};
+use crate::hint::{HintType, WordleHint};
+use crate::normalize::{normalize_word, word_length};
+use pyo3::prelude::*;
+use std::collections::{BTreeMap, HashMap};
+
+/// Fixed-size set of word indices, one bit per word
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Bitset {
+    blocks: Vec<u64>,
+    len: usize,
+}
+
+impl Bitset {
+    /// Empty set over `len` words
+    pub fn new(len: usize) -> Self {
+        Bitset {
+            blocks: vec![0; len.div_ceil(64)],
+            len,
+        }
+    }
+
+    /// Set holding every one of the `len` words
+    pub fn full(len: usize) -> Self {
+        let mut set = Bitset {
+            blocks: vec![u64::MAX; len.div_ceil(64)],
+            len,
+        };
+        if !len.is_multiple_of(64) {
+            if let Some(last) = set.blocks.last_mut() {
+                *last = (1 << (len % 64)) - 1;
+            }
+        }
+        set
+    }
+
+    pub fn insert(&mut self, index: usize) {
+        self.blocks[index / 64] |= 1 << (index % 64);
+    }
+
+    pub fn contains(&self, index: usize) -> bool {
+        index < self.len && self.blocks[index / 64] & (1 << (index % 64)) != 0
+    }
+
+    /// Keep only the words also in `other`
+    pub fn intersect(&mut self, other: &Bitset) {
+        for (block, other) in self.blocks.iter_mut().zip(&other.blocks) {
+            *block &= other;
+        }
+    }
+
+    /// Drop the words in `other`
+    pub fn subtract(&mut self, other: &Bitset) {
+        for (block, other) in self.blocks.iter_mut().zip(&other.blocks) {
+            *block &= !other;
+        }
+    }
+
+    pub fn count(&self) -> usize {
+        self.blocks
+            .iter()
+            .map(|block| block.count_ones() as usize)
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.blocks.iter().all(|block| *block == 0)
+    }
+
+    /// Indices of the words in the set, in increasing order
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.blocks.iter().enumerate().flat_map(|(i, block)| {
+            let mut block = *block;
+            std::iter::from_fn(move || {
+                if block == 0 {
+                    return None;
+                }
+                let bit = block.trailing_zeros() as usize;
+                block &= block - 1;
+                Some(i * 64 + bit)
+            })
+        })
+    }
+}
+
+/// A conjunction of letter conditions, answered by LetterIndex::query. Positions are 0-based
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LetterQuery {
+    pub word_length: Option<usize>,
+    /// Letter required at a position
+    pub greens: Vec<(usize, char)>,
+    /// Letter forbidden at a position
+    pub excluded: Vec<(char, usize)>,
+    /// Letter required at least this many times
+    pub min_counts: Vec<(char, usize)>,
+    /// Letter allowed at most this many times (0 for absent letters)
+    pub max_counts: Vec<(char, usize)>,
+}
+
+impl LetterQuery {
+    /// Words a set of constraints allows
+    pub fn from_constraints(constraints: &Constraints) -> Self {
+        let length = constraints.word_length();
+        LetterQuery {
+            word_length: length,
+            greens: (0..length.unwrap_or(0))
+                .filter_map(|position| Some((position, constraints.green_at(position)?)))
+                .collect(),
+            excluded: constraints
+                .excluded_positions()
+                .into_iter()
+                .flat_map(|(letter, positions)| {
+                    positions
+                        .into_iter()
+                        .map(move |position| (letter, position))
+                })
+                .collect(),
+            min_counts: constraints.min_counts().into_iter().collect(),
+            max_counts: constraints.max_counts().into_iter().collect(),
+        }
+    }
+
+    /// Guesses allowed by NYT hard-mode rules after `history` (see check_hard_mode): every
+    /// revealed green in place and every revealed letter at least as often as one hint showed it
+    pub fn hard_mode(history: &[WordleHint]) -> Self {
+        let mut greens = vec![];
+        let mut min_counts: BTreeMap<char, usize> = BTreeMap::new();
+        for hint in history {
+            let mut revealed: BTreeMap<char, usize> = BTreeMap::new();
+            for (position, (letter, hint_type)) in
+                hint.word().chars().zip(hint.hint_types()).enumerate()
+            {
+                if *hint_type == HintType::Correct {
+                    greens.push((position, letter));
+                }
+                if *hint_type != HintType::Absent {
+                    *revealed.entry(letter).or_default() += 1;
+                }
+            }
+            for (letter, count) in revealed {
+                let min = min_counts.entry(letter).or_default();
+                *min = (*min).max(count);
+            }
+        }
+        LetterQuery {
+            greens,
+            min_counts: min_counts.into_iter().collect(),
+            ..LetterQuery::default()
+        }
+    }
+}
+
+/// Precomputed bitsets over a word list: for each (position, letter) the words with that
+/// letter there, and for each (letter, n) the words containing the letter at least n times.
+/// Any LetterQuery (and so any Constraints) is answered with a handful of ANDs instead of a
+/// pass over every word
+#[pyclass(frozen, module = "wordle_tournament_lib")]
+#[derive(Clone, Debug)]
+pub struct LetterIndex {
+    len: usize,
+    by_length: HashMap<usize, Bitset>,
+    at_position: HashMap<(usize, char), Bitset>,
+    at_least: HashMap<(char, usize), Bitset>,
+}
+
+#[pymethods]
+impl LetterIndex {
+    /// Index over any list of words; results are indices into that list
+    #[new]
+    fn new_letter_index(words: Vec<String>) -> Self {
+        let words: Vec<String> = words
+            .iter()
+            .map(|word| normalize_word(word, false, ""))
+            .collect();
+        LetterIndex::new(&words)
+    }
+
+    /// Indices of the words matching every condition given, in list order. greens maps
+    /// 0-based positions to letters, excluded maps letters to positions they can't occupy,
+    /// and min_counts/max_counts bound how often letters appear
+    #[pyo3(name = "query")]
+    #[pyo3(signature = (
+        word_length = None,
+        greens = BTreeMap::new(),
+        excluded = BTreeMap::new(),
+        min_counts = BTreeMap::new(),
+        max_counts = BTreeMap::new()
+    ))]
+    fn query_py(
+        &self,
+        word_length: Option<usize>,
+        greens: BTreeMap<usize, char>,
+        excluded: BTreeMap<char, Vec<usize>>,
+        min_counts: BTreeMap<char, usize>,
+        max_counts: BTreeMap<char, usize>,
+    ) -> Vec<usize> {
+        let query = LetterQuery {
+            word_length,
+            greens: greens.into_iter().collect(),
+            excluded: excluded
+                .into_iter()
+                .flat_map(|(letter, positions)| {
+                    positions
+                        .into_iter()
+                        .map(move |position| (letter, position))
+                })
+                .collect(),
+            min_counts: min_counts.into_iter().collect(),
+            max_counts: max_counts.into_iter().collect(),
+        };
+        self.query(&query).iter().collect()
+    }
+
+    /// Indices of the words of word_length letters that could still be the answer after
+    /// these hints
+    #[pyo3(name = "candidates")]
+    fn candidates_py(&self, hints: Vec<WordleHint>, word_length: usize) -> PyResult<Vec<usize>> {
+        Ok(self.candidates(&hints, word_length)?.iter().collect())
+    }
+
+    /// Indices of the words of word_length letters that are legal hard-mode guesses after
+    /// these hints
+    #[pyo3(name = "hard_mode_guesses")]
+    fn hard_mode_guesses_py(&self, hints: Vec<WordleHint>, word_length: usize) -> Vec<usize> {
+        self.hard_mode_guesses(&hints, word_length).iter().collect()
+    }
+
+    fn __len__(&self) -> usize {
+        self.len
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "LetterIndex(words={}, bitsets={})",
+            self.len,
+            self.at_position.len() + self.at_least.len()
+        )
+    }
+}
+
+impl LetterIndex {
+    /// Index over (normalized) words
+    pub fn new<S: AsRef<str>>(words: &[S]) -> Self {
+        let len = words.len();
+        let mut index = LetterIndex {
+            len,
+            by_length: HashMap::new(),
+            at_position: HashMap::new(),
+            at_least: HashMap::new(),
+        };
+        for (i, word) in words.iter().enumerate() {
+            let word = word.as_ref();
+            index
+                .by_length
+                .entry(word_length(word))
+                .or_insert_with(|| Bitset::new(len))
+                .insert(i);
+            let mut counts: HashMap<char, usize> = HashMap::new();
+            for (position, letter) in word.chars().enumerate() {
+                index
+                    .at_position
+                    .entry((position, letter))
+                    .or_insert_with(|| Bitset::new(len))
+                    .insert(i);
+                let count = counts.entry(letter).or_default();
+                *count += 1;
+                index
+                    .at_least
+                    .entry((letter, *count))
+                    .or_insert_with(|| Bitset::new(len))
+                    .insert(i);
+            }
+        }
+        index
+    }
+
+    /// Number of indexed words
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Words matching every condition of the query
+    pub fn query(&self, query: &LetterQuery) -> Bitset {
+        let mut set = match query.word_length {
+            Some(length) => self.of_length(length),
+            None => Bitset::full(self.len),
+        };
+        for (position, letter) in &query.greens {
+            set.intersect(&self.letter_at(*position, *letter));
+        }
+        for (letter, position) in &query.excluded {
+            if let Some(words) = self.at_position.get(&(*position, *letter)) {
+                set.subtract(words);
+            }
+        }
+        for (letter, count) in &query.min_counts {
+            if *count > 0 {
+                set.intersect(&self.at_least(*letter, *count));
+            }
+        }
+        for (letter, count) in &query.max_counts {
+            if let Some(words) = self.at_least.get(&(*letter, count + 1)) {
+                set.subtract(words);
+            }
+        }
+        set
+    }
+
+    /// Words of word_length letters the constraints allow; same result as filtering with
+    /// Constraints::allows
+    pub fn matching(&self, constraints: &Constraints, word_length: usize) -> Bitset {
+        if constraints
+            .word_length()
+            .is_some_and(|length| length != word_length)
+        {
+            return Bitset::new(self.len);
+        }
+        let mut query = LetterQuery::from_constraints(constraints);
+        query.word_length = Some(word_length);
+        self.query(&query)
+    }
+
+    /// Words of word_length letters that could still be the answer after `history`. Fails if
+    /// the hints have different lengths
+    pub fn candidates(
+        &self,
+        history: &[WordleHint],
+        word_length: usize,
+    ) -> Result<Bitset, HintLengthMismatch> {
+        Ok(self.matching(&Constraints::from_hints(history)?, word_length))
+    }
+
+    /// Words of word_length letters that check_hard_mode accepts after `history`
+    pub fn hard_mode_guesses(&self, history: &[WordleHint], word_length: usize) -> Bitset {
+        let mut query = LetterQuery::hard_mode(history);
+        query.word_length = Some(word_length);
+        self.query(&query)
+    }
+
+    /// Words with this many letters
+    pub fn of_length(&self, length: usize) -> Bitset {
+        self.lookup(self.by_length.get(&length))
+    }
+
+    /// Words with `letter` at 0-based `position`
+    pub fn letter_at(&self, position: usize, letter: char) -> Bitset {
+        self.lookup(self.at_position.get(&(position, letter)))
+    }
+
+    /// Words containing `letter` at least `count` times
+    pub fn at_least(&self, letter: char, count: usize) -> Bitset {
+        match count {
+            0 => Bitset::full(self.len),
+            _ => self.lookup(self.at_least.get(&(letter, count))),
+        }
+    }
+
+    fn lookup(&self, words: Option<&Bitset>) -> Bitset {
+        words.cloned().unwrap_or_else(|| Bitset::new(self.len))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::WORD_LENGTH;
+    use crate::corpus::Corpus;
+    use crate::grade::grade_guess;
+    use crate::hard_mode::check_hard_mode;
+
+    #[test]
+    fn test_bitset_ops() {
+        let mut set = Bitset::full(70);
+        assert_eq!(set.count(), 70);
+        assert!(set.contains(69) && !set.contains(70));
+
+        let mut odd = Bitset::new(70);
+        for i in (1..70).step_by(2) {
+            odd.insert(i);
+        }
+        set.subtract(&odd);
+        assert_eq!(set.count(), 35);
+        set.intersect(&odd);
+        assert!(set.is_empty());
+        assert_eq!(odd.iter().take(3).collect::<Vec<_>>(), [1, 3, 5]);
+        assert_eq!(odd.iter().last(), Some(69));
+    }
+
+    #[test]
+    fn test_query() {
+        let words = ["beset", "ceded", "apace", "geese", "bases"];
+        let index = LetterIndex::new(&words);
+        // Two or more E's and no S
+        let query = LetterQuery {
+            min_counts: vec![('e', 2)],
+            max_counts: vec![('s', 0)],
+            ..LetterQuery::default()
+        };
+        assert_eq!(index.query(&query).iter().collect::<Vec<_>>(), [1]);
+
+        // Third letter S and two or more E's
+        let query = LetterQuery {
+            greens: vec![(2, 's')],
+            min_counts: vec![('e', 2)],
+            ..LetterQuery::default()
+        };
+        assert_eq!(index.query(&query).iter().collect::<Vec<_>>(), [0]);
+    }
+
+    #[test]
+    fn test_matches_constraints_and_hard_mode() {
+        let corpus = Corpus::embedded();
+        let words = corpus.guesses_of_length(WORD_LENGTH);
+        let index = LetterIndex::new(&words);
+        let histories = [
+            vec![],
+            vec![grade_guess("crane", "cigar")],
+            vec![grade_guess("array", "alarm")],
+            vec![grade_guess("geese", "abide"), grade_guess("slate", "abide")],
+        ];
+        for history in &histories {
+            let constraints = Constraints::from_hints(history).unwrap();
+            let expected: Vec<usize> = (0..words.len())
+                .filter(|i| word_length(words[*i]) == WORD_LENGTH && constraints.allows(words[*i]))
+                .collect();
+            let candidates: Vec<usize> = index
+                .candidates(history, WORD_LENGTH)
+                .unwrap()
+                .iter()
+                .collect();
+            assert_eq!(candidates, expected, "{:?}", history);
+
+            let expected: Vec<usize> = (0..words.len())
+                .filter(|i| {
+                    word_length(words[*i]) == WORD_LENGTH
+                        && check_hard_mode(words[*i], history).is_ok()
+                })
+                .collect();
+            let guesses: Vec<usize> = index
+                .hard_mode_guesses(history, WORD_LENGTH)
+                .iter()
+                .collect();
+            assert_eq!(guesses, expected, "{:?}", history);
+        }
+    }
+
+    #[test]
+    fn test_candidates_reject_mixed_lengths() {
+        let index = LetterIndex::new(&["crane", "planet"]);
+        let history = [
+            grade_guess("crane", "cigar"),
+            grade_guess("planet", "planet"),
+        ];
+        assert!(index.candidates(&history, WORD_LENGTH).is_err());
+    }
+}
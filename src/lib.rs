@@ -1,10 +1,28 @@
 use pyo3::prelude::*;
 
+pub mod adversary;
 pub mod backend_client;
 pub mod common;
+pub mod consistency;
+pub mod constraints;
 pub mod corpus;
+pub mod count_hint;
+pub mod fibble;
+pub mod frequency;
+pub mod game_record;
+pub mod game_state;
 pub mod grade;
+pub mod hard_mode;
 pub mod hint;
+pub mod history;
+pub mod keyboard;
+pub mod letter_index;
+pub mod multi_board;
+pub mod normalize;
+pub mod pattern_matrix;
+pub mod render;
+pub mod sampling;
+pub mod share;
 pub mod utils;
 mod wordle_bot_base;
 
@@ -12,7 +30,28 @@ mod wordle_bot_base;
 #[pymodule]
 fn wordle_tournament_lib(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<hint::WordleHint>()?;
+    m.add_class::<count_hint::CountHint>()?;
     m.add_class::<wordle_bot_base::UChicagoWordleBotBase>()?;
+    m.add_class::<pattern_matrix::PatternMatrix>()?;
+    m.add_class::<consistency::HistoryCheck>()?;
+    m.add_class::<constraints::Constraints>()?;
+    m.add_class::<game_record::GameRecord>()?;
+    m.add_class::<history::GuessHistory>()?;
+    m.add_class::<game_state::GameState>()?;
+    m.add_class::<keyboard::KeyboardState>()?;
+    m.add_class::<corpus::Corpus>()?;
+    m.add_class::<frequency::FrequencyTable>()?;
+    m.add_class::<letter_index::LetterIndex>()?;
+    // Lets GuessHistory stand in wherever a list of hints is accepted
+    pyo3::types::PySequence::register::<history::GuessHistory>(m.py())?;
+    m.add_function(pyo3::wrap_pyfunction!(hint::restore_hint, m)?)?;
+    m.add_function(pyo3::wrap_pyfunction!(game_record::save_game_records, m)?)?;
+    m.add_function(pyo3::wrap_pyfunction!(game_record::load_game_records, m)?)?;
+    m.add_function(pyo3::wrap_pyfunction!(share::parse_share_grid_py, m)?)?;
+    m.add_function(pyo3::wrap_pyfunction!(corpus::guess_corpus, m)?)?;
+    m.add_function(pyo3::wrap_pyfunction!(corpus::answer_list, m)?)?;
+    m.add_function(pyo3::wrap_pyfunction!(corpus::is_valid_word_py, m)?)?;
+    m.add_function(pyo3::wrap_pyfunction!(corpus::word_list_hashes, m)?)?;
     // Avoiding exposure of grade_guess for now because it makes things too easy
     // m.add_function(pyo3::wrap_pyfunction!(grade::grade_guess_py, m)?)?;
     Ok(())
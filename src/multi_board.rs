@@ -0,0 +1,117 @@
+use crate::common::{MAX_BOARDS, MAX_GUESSES};
+use crate::hint::WordleHint;
+
+/// Total guesses allowed to clear a game of num_boards boards: one extra per extra board,
+/// like Dordle/Quordle/Octordle
+pub fn max_multi_board_guesses(num_boards: usize) -> usize {
+    MAX_GUESSES + num_boards - 1
+}
+
+pub fn is_valid_num_boards(num_boards: usize) -> bool {
+    (1..=MAX_BOARDS).contains(&num_boards)
+}
+
+/// Hint histories for one multi-board game. Every guess goes to every open board; a board
+/// closes once it receives a fully correct hint and gets no further hints after that
+pub struct MultiBoardGame {
+    histories: Vec<Vec<WordleHint>>,
+    num_guesses: usize,
+    cleared_after: Option<usize>,
+}
+
+impl MultiBoardGame {
+    pub fn new(num_boards: usize) -> Self {
+        MultiBoardGame {
+            histories: vec![vec![]; num_boards],
+            num_guesses: 0,
+            cleared_after: None,
+        }
+    }
+
+    pub fn num_boards(&self) -> usize {
+        self.histories.len()
+    }
+
+    /// Guesses made so far
+    pub fn num_guesses(&self) -> usize {
+        self.num_guesses
+    }
+
+    pub fn histories(&self) -> &[Vec<WordleHint>] {
+        &self.histories
+    }
+
+    pub fn is_board_solved(&self, board: usize) -> bool {
+        self.histories[board]
+            .last()
+            .is_some_and(|hint| hint.is_fully_correct())
+    }
+
+    pub fn is_cleared(&self) -> bool {
+        self.cleared_after.is_some()
+    }
+
+    /// Total guesses it took to clear every board, if they have all been cleared
+    pub fn cleared_after(&self) -> Option<usize> {
+        self.cleared_after
+    }
+
+    /// Indices of boards that still need solving
+    pub fn open_boards(&self) -> Vec<usize> {
+        (0..self.num_boards())
+            .filter(|board| !self.is_board_solved(*board))
+            .collect()
+    }
+
+    /// Record one guess. `hints` holds the hint for each open board, in board order
+    pub fn record_guess(&mut self, hints: Vec<WordleHint>) {
+        let open_boards = self.open_boards();
+        assert_eq!(
+            open_boards.len(),
+            hints.len(),
+            "Expected one hint per open board"
+        );
+
+        self.num_guesses += 1;
+        for (board, hint) in open_boards.into_iter().zip(hints) {
+            self.histories[board].push(hint);
+        }
+        if self.cleared_after.is_none() && self.open_boards().is_empty() {
+            self.cleared_after = Some(self.num_guesses);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grade::grade_guess;
+
+    #[test]
+    fn test_boards_close_independently() {
+        let answers = ["cigar", "robot"];
+        let mut game = MultiBoardGame::new(answers.len());
+        for guess in ["robot", "crane", "cigar"] {
+            let hints = game
+                .open_boards()
+                .into_iter()
+                .map(|board| grade_guess(guess, answers[board]))
+                .collect();
+            game.record_guess(hints);
+        }
+
+        assert!(game.is_cleared());
+        assert_eq!(game.cleared_after(), Some(3));
+        assert_eq!(game.histories()[0].len(), 3);
+        // Board 1 closed on the first guess and received no more hints
+        assert_eq!(game.histories()[1].len(), 1);
+    }
+
+    #[test]
+    fn test_guess_limit_grows_with_boards() {
+        assert_eq!(max_multi_board_guesses(1), MAX_GUESSES);
+        assert_eq!(max_multi_board_guesses(4), MAX_GUESSES + 3);
+        assert!(!is_valid_num_boards(0));
+        assert!(is_valid_num_boards(8));
+    }
+}
@@ -0,0 +1,53 @@
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+/// Number of letters in a word, counted in Unicode scalar values. Words should be
+/// normalized first so that e.g. a decomposed "n" + "◌̃" counts as the single letter "ñ"
+pub fn word_length(word: &str) -> usize {
+    word.chars().count()
+}
+
+/// Canonical form of a word for grading and corpus lookups: NFC-composed and trimmed.
+/// With strip_accents, diacritics are removed ("á" -> "a") except on letters in `keep`,
+/// so e.g. Spanish lists can fold accents while keeping "ñ" as its own letter
+pub fn normalize_word(word: &str, strip_accents: bool, keep: &str) -> String {
+    let word = word.trim();
+    if !strip_accents {
+        return word.nfc().collect();
+    }
+
+    let keep: Vec<char> = keep.nfc().collect();
+    word.nfc()
+        .map(|letter| match keep.contains(&letter) {
+            true => letter.to_string(),
+            false => letter
+                .to_string()
+                .nfd()
+                .filter(|c| !is_combining_mark(*c))
+                .nfc()
+                .collect(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_composes_decomposed_letters() {
+        let decomposed = "nin\u{0303}o";
+        assert_eq!(word_length(decomposed), 5);
+        let normalized = normalize_word(decomposed, false, "");
+        assert_eq!(normalized, "niño");
+        assert_eq!(word_length(&normalized), 4);
+    }
+
+    #[test]
+    fn test_strip_accents() {
+        assert_eq!(normalize_word("canción", true, ""), "cancion");
+        assert_eq!(normalize_word("Straße", true, ""), "Straße");
+        assert_eq!(normalize_word("añejó", true, "ñ"), "añejo");
+        assert_eq!(normalize_word("añejó", false, ""), "añejó");
+    }
+}
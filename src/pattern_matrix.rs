@@ -0,0 +1,431 @@
+use crate::common::WORD_LENGTH;
+use crate::corpus::Corpus;
+use crate::grade::grade_code;
+use crate::hint::{PatternCode, WordleHint};
+use crate::utils::fnv1a_hash;
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+const MAGIC: &[u8; 4] = b"WTPM";
+/// Bump whenever the on-disk layout or the grading semantics change
+const FORMAT_VERSION: u32 = 1;
+const HEADER_LEN: usize = 4 + 4 + 8 + 4 + 4;
+
+/// A 5-letter word a pattern matrix can't hold: pattern codes are computed bytewise, so
+/// every word must be ASCII
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NonAsciiWord {
+    pub word: String,
+}
+
+impl fmt::Display for NonAsciiWord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Pattern matrices need ASCII words, got '{}'", self.word)
+    }
+}
+
+impl From<NonAsciiWord> for PyErr {
+    fn from(err: NonAsciiWord) -> PyErr {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(err.to_string())
+    }
+}
+
+/// Precomputed guess x answer table of PatternCodes. Row i is the corpus's i-th 5-letter
+/// guess and column j its j-th 5-letter answer, both in file order
+#[pyclass]
+pub struct PatternMatrix {
+    guesses: Vec<String>,
+    answers: Vec<String>,
+    guess_indices: HashMap<String, usize>,
+    answer_indices: HashMap<String, usize>,
+    list_hash: u64,
+    patterns: Vec<PatternCode>,
+}
+
+#[pymethods]
+impl PatternMatrix {
+    /// Build the matrix for a corpus (the embedded one by default)
+    #[staticmethod]
+    #[pyo3(name = "build")]
+    #[pyo3(signature = (corpus = None))]
+    fn build_py(corpus: Option<Corpus>) -> PyResult<Self> {
+        let corpus = corpus.unwrap_or_else(Corpus::embedded);
+        Ok(PatternMatrix::from_corpus(&corpus)?)
+    }
+
+    /// Load the matrix from path if it was built for the corpus's word lists (the embedded
+    /// ones by default), otherwise build it and save it to path
+    #[staticmethod]
+    #[pyo3(name = "load_or_build")]
+    #[pyo3(signature = (path, corpus = None))]
+    fn load_or_build_py(path: &str, corpus: Option<Corpus>) -> PyResult<Self> {
+        let corpus = corpus.unwrap_or_else(Corpus::embedded);
+        check_ascii_words(&corpus)?;
+        PatternMatrix::load_or_build(path, &corpus).map_err(|e| {
+            PyErr::new::<pyo3::exceptions::PyIOError, _>(format!(
+                "Failed to load or build pattern matrix at {}: {}",
+                path, e
+            ))
+        })
+    }
+
+    #[pyo3(name = "save")]
+    fn save_py(&self, path: &str) -> PyResult<()> {
+        self.save(path).map_err(|e| {
+            PyErr::new::<pyo3::exceptions::PyIOError, _>(format!(
+                "Failed to save pattern matrix to {}: {}",
+                path, e
+            ))
+        })
+    }
+
+    #[getter]
+    pub fn num_guesses(&self) -> usize {
+        self.guesses.len()
+    }
+
+    #[getter]
+    pub fn num_answers(&self) -> usize {
+        self.answers.len()
+    }
+
+    #[getter]
+    pub fn list_hash(&self) -> u64 {
+        self.list_hash
+    }
+
+    pub fn guess_index(&self, word: &str) -> Option<usize> {
+        self.guess_indices.get(word).copied()
+    }
+
+    pub fn answer_index(&self, word: &str) -> Option<usize> {
+        self.answer_indices.get(word).copied()
+    }
+
+    #[pyo3(name = "pattern")]
+    fn pattern_py(&self, guess_idx: usize, answer_idx: usize) -> PyResult<PatternCode> {
+        self.check_indices(guess_idx, answer_idx)?;
+        Ok(self.pattern(guess_idx, answer_idx))
+    }
+
+    /// The hint a guess would receive against an answer, looked up by word
+    fn hint(&self, guess: &str, answer: &str) -> PyResult<WordleHint> {
+        match (self.guess_index(guess), self.answer_index(answer)) {
+            (Some(guess_idx), Some(answer_idx)) => Ok(WordleHint::from_pattern_code(
+                guess.to_string(),
+                self.pattern(guess_idx, answer_idx),
+            )),
+            _ => Err(PyErr::new::<pyo3::exceptions::PyKeyError, _>(format!(
+                "({}, {}) is not in the pattern matrix",
+                guess, answer
+            ))),
+        }
+    }
+
+    /// One guess's patterns against every answer, as bytes (numpy.frombuffer friendly)
+    #[pyo3(name = "row")]
+    fn row_py<'py>(&self, py: Python<'py>, guess_idx: usize) -> PyResult<Bound<'py, PyBytes>> {
+        if guess_idx >= self.guesses.len() {
+            return Err(PyErr::new::<pyo3::exceptions::PyIndexError, _>(format!(
+                "Guess index {} out of range for {} guesses",
+                guess_idx,
+                self.guesses.len()
+            )));
+        }
+        Ok(PyBytes::new(py, self.row(guess_idx)))
+    }
+
+    /// The whole matrix as row-major bytes of shape (num_guesses, num_answers)
+    fn as_bytes<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new(py, &self.patterns)
+    }
+
+    #[getter]
+    fn guesses(&self) -> Vec<String> {
+        self.guesses.clone()
+    }
+
+    #[getter]
+    fn answers(&self) -> Vec<String> {
+        self.answers.clone()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "PatternMatrix(num_guesses={}, num_answers={}, list_hash={:#018x})",
+            self.num_guesses(),
+            self.num_answers(),
+            self.list_hash
+        )
+    }
+}
+
+impl PatternMatrix {
+    /// Grade every guess against every answer. All words must be WORD_LENGTH bytes long
+    pub fn build(guesses: &[&str], answers: &[&str]) -> Self {
+        let answer_bytes: Vec<&[u8; WORD_LENGTH]> = answers.iter().map(|w| as_word(w)).collect();
+
+        let mut patterns = Vec::with_capacity(guesses.len() * answers.len());
+        for guess in guesses {
+            let guess_bytes = as_word(guess);
+            patterns.extend(
+                answer_bytes
+                    .iter()
+                    .map(|answer| grade_code(guess_bytes, answer)),
+            );
+        }
+
+        PatternMatrix::from_parts(guesses, answers, patterns)
+    }
+
+    /// Build the matrix for a corpus's 5-letter guesses and answers. Fails if any of them
+    /// isn't ASCII
+    pub fn from_corpus(corpus: &Corpus) -> Result<Self, NonAsciiWord> {
+        check_ascii_words(corpus)?;
+        Ok(PatternMatrix::build(
+            &corpus.guesses_of_length(WORD_LENGTH),
+            &corpus.answers_of_length(WORD_LENGTH),
+        ))
+    }
+
+    pub fn pattern(&self, guess_idx: usize, answer_idx: usize) -> PatternCode {
+        self.patterns[guess_idx * self.answers.len() + answer_idx]
+    }
+
+    pub fn row(&self, guess_idx: usize) -> &[PatternCode] {
+        let num_answers = self.answers.len();
+        &self.patterns[guess_idx * num_answers..(guess_idx + 1) * num_answers]
+    }
+
+    /// Write the matrix as: magic, format version, list hash, rows, cols, row-major codes
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let mut bytes = Vec::with_capacity(HEADER_LEN + self.patterns.len());
+        bytes.extend_from_slice(MAGIC);
+        bytes.extend_from_slice(&FORMAT_VERSION.to_le_bytes());
+        bytes.extend_from_slice(&self.list_hash.to_le_bytes());
+        bytes.extend_from_slice(&(self.guesses.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&(self.answers.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&self.patterns);
+        fs::write(path, bytes)
+    }
+
+    /// Read a matrix saved for exactly these word lists. Fails with InvalidData if the
+    /// file is malformed, from another format version, or built from different lists
+    pub fn load<P: AsRef<Path>>(path: P, guesses: &[&str], answers: &[&str]) -> io::Result<Self> {
+        let bytes = fs::read(path)?;
+        if bytes.len() < HEADER_LEN || &bytes[0..4] != MAGIC {
+            return Err(invalid_data("not a pattern matrix file".to_string()));
+        }
+
+        let version = u32::from_le_bytes(bytes[4..8].try_into().unwrap());
+        if version != FORMAT_VERSION {
+            return Err(invalid_data(format!(
+                "format version {} does not match expected version {}",
+                version, FORMAT_VERSION
+            )));
+        }
+
+        let list_hash = u64::from_le_bytes(bytes[8..16].try_into().unwrap());
+        if list_hash != hash_word_lists(guesses, answers) {
+            return Err(invalid_data(
+                "file was built from different word lists".to_string(),
+            ));
+        }
+
+        let rows = u32::from_le_bytes(bytes[16..20].try_into().unwrap()) as usize;
+        let cols = u32::from_le_bytes(bytes[20..24].try_into().unwrap()) as usize;
+        if rows != guesses.len() || cols != answers.len() || bytes.len() != HEADER_LEN + rows * cols
+        {
+            return Err(invalid_data(format!(
+                "expected {} x {} patterns",
+                guesses.len(),
+                answers.len()
+            )));
+        }
+
+        Ok(PatternMatrix::from_parts(
+            guesses,
+            answers,
+            bytes[HEADER_LEN..].to_vec(),
+        ))
+    }
+
+    /// Load the cached matrix for a corpus's word lists, rebuilding and re-saving it if the
+    /// cache is missing or stale. Fails with InvalidInput if a 5-letter word isn't ASCII
+    pub fn load_or_build<P: AsRef<Path>>(path: P, corpus: &Corpus) -> io::Result<Self> {
+        check_ascii_words(corpus)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e.to_string()))?;
+        let guesses = corpus.guesses_of_length(WORD_LENGTH);
+        let answers = corpus.answers_of_length(WORD_LENGTH);
+        match PatternMatrix::load(&path, &guesses, &answers) {
+            Ok(matrix) => Ok(matrix),
+            Err(e)
+                if matches!(
+                    e.kind(),
+                    io::ErrorKind::NotFound | io::ErrorKind::InvalidData
+                ) =>
+            {
+                let matrix = PatternMatrix::build(&guesses, &answers);
+                matrix.save(&path)?;
+                Ok(matrix)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    fn from_parts(guesses: &[&str], answers: &[&str], patterns: Vec<PatternCode>) -> Self {
+        let index = |words: &[&str]| -> HashMap<String, usize> {
+            words
+                .iter()
+                .enumerate()
+                .map(|(i, word)| (word.to_string(), i))
+                .collect()
+        };
+        PatternMatrix {
+            guesses: guesses.iter().map(|w| w.to_string()).collect(),
+            answers: answers.iter().map(|w| w.to_string()).collect(),
+            guess_indices: index(guesses),
+            answer_indices: index(answers),
+            list_hash: hash_word_lists(guesses, answers),
+            patterns,
+        }
+    }
+
+    fn check_indices(&self, guess_idx: usize, answer_idx: usize) -> PyResult<()> {
+        if guess_idx >= self.guesses.len() || answer_idx >= self.answers.len() {
+            return Err(PyErr::new::<pyo3::exceptions::PyIndexError, _>(format!(
+                "Index ({}, {}) out of range for {} x {} pattern matrix",
+                guess_idx,
+                answer_idx,
+                self.guesses.len(),
+                self.answers.len()
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Hash of both word lists, in order, used to detect stale cache files
+fn hash_word_lists(guesses: &[&str], answers: &[&str]) -> u64 {
+    let mut content = guesses.join("\n");
+    content.push('\0');
+    content.push_str(&answers.join("\n"));
+    fnv1a_hash(content.as_bytes())
+}
+
+/// Pattern codes are computed bytewise, so every 5-letter word must be ASCII
+fn check_ascii_words(corpus: &Corpus) -> Result<(), NonAsciiWord> {
+    let words = corpus.guesses_of_length(WORD_LENGTH);
+    let answers = corpus.answers_of_length(WORD_LENGTH);
+    match words.iter().chain(&answers).find(|word| !word.is_ascii()) {
+        Some(word) => Err(NonAsciiWord {
+            word: word.to_string(),
+        }),
+        None => Ok(()),
+    }
+}
+
+fn as_word(word: &str) -> &[u8; WORD_LENGTH] {
+    word.as_bytes()
+        .try_into()
+        .unwrap_or_else(|_| panic!("Word '{}' is not {} letters", word, WORD_LENGTH))
+}
+
+fn invalid_data(msg: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grade::grade_guess;
+
+    const GUESSES: [&str; 4] = ["crane", "roost", "allee", "array"];
+    const ANSWERS: [&str; 3] = ["robot", "apple", "alarm"];
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("{}-{}.bin", name, std::process::id()))
+    }
+
+    #[test]
+    fn test_matches_grade_guess() {
+        let matrix = PatternMatrix::build(&GUESSES, &ANSWERS);
+        for (i, guess) in GUESSES.iter().enumerate() {
+            for (j, answer) in ANSWERS.iter().enumerate() {
+                assert_eq!(
+                    matrix.pattern(i, j),
+                    grade_guess(guess, answer).pattern_code().unwrap()
+                );
+            }
+            assert_eq!(matrix.row(i).len(), ANSWERS.len());
+        }
+        assert_eq!(matrix.guess_index("allee"), Some(2));
+        assert_eq!(matrix.answer_index("crane"), None);
+    }
+
+    #[test]
+    fn test_save_and_load_round_trip() {
+        let path = temp_path("pattern-matrix-round-trip");
+        let matrix = PatternMatrix::build(&GUESSES, &ANSWERS);
+        matrix.save(&path).unwrap();
+        let loaded = PatternMatrix::load(&path, &GUESSES, &ANSWERS).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.patterns, matrix.patterns);
+        assert_eq!(loaded.list_hash, matrix.list_hash);
+    }
+
+    #[test]
+    fn test_load_rejects_different_word_lists() {
+        let path = temp_path("pattern-matrix-stale");
+        PatternMatrix::build(&GUESSES, &ANSWERS)
+            .save(&path)
+            .unwrap();
+        let err = PatternMatrix::load(&path, &GUESSES, &ANSWERS[..2])
+            .err()
+            .unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_load_or_build_for_corpus() {
+        // Words of other lengths in the corpus are left out of the matrix
+        let corpus = Corpus::new(GUESSES.iter().chain(&["cat"]), ANSWERS, false, "");
+        let path = temp_path("pattern-matrix-corpus");
+        let _ = fs::remove_file(&path);
+        let built = PatternMatrix::load_or_build(&path, &corpus).unwrap();
+        assert_eq!(
+            built.patterns,
+            PatternMatrix::build(&GUESSES, &ANSWERS).patterns
+        );
+
+        // Change the last cached pattern: only a matrix read back from disk can have it
+        let mut bytes = fs::read(&path).unwrap();
+        *bytes.last_mut().unwrap() ^= 1;
+        fs::write(&path, &bytes).unwrap();
+        let loaded = PatternMatrix::load_or_build(&path, &corpus).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.patterns, bytes[HEADER_LEN..]);
+        assert_ne!(loaded.patterns, built.patterns);
+    }
+
+    #[test]
+    fn test_from_corpus_rejects_non_ascii() {
+        let corpus = Corpus::new(["crane", "niños"], ["crane"], false, "");
+        assert_eq!(
+            PatternMatrix::from_corpus(&corpus).err(),
+            Some(NonAsciiWord {
+                word: "niños".to_string()
+            })
+        );
+        let path = temp_path("pattern-matrix-non-ascii");
+        let err = PatternMatrix::load_or_build(&path, &corpus).err().unwrap();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert!(!path.exists());
+    }
+}
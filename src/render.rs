@@ -0,0 +1,312 @@
+use crate::hint::{HintType, WordleHint};
+use crate::keyboard::KeyboardState;
+use pyo3::prelude::*;
+
+/// How hints are drawn
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenderStyle {
+    /// Spaced uppercase letters over a row of emoji squares
+    Emoji,
+    /// Letters on colored tiles, using 24-bit ANSI escape codes
+    Ansi,
+    /// A Wordle-style grid of tiles, for notebooks
+    Html,
+}
+
+impl RenderStyle {
+    pub fn parse(style: &str) -> PyResult<Self> {
+        match style {
+            "emoji" => Ok(RenderStyle::Emoji),
+            "ansi" => Ok(RenderStyle::Ansi),
+            "html" => Ok(RenderStyle::Html),
+            _ => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Unknown render style '{}' - must be 'emoji', 'ansi' or 'html'",
+                style
+            ))),
+        }
+    }
+}
+
+/// Tile colors. The high-contrast palette is NYT's colorblind mode: orange for correct and
+/// blue for present
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Palette {
+    Standard,
+    HighContrast,
+}
+
+impl Palette {
+    pub fn new(high_contrast: bool) -> Self {
+        match high_contrast {
+            true => Palette::HighContrast,
+            false => Palette::Standard,
+        }
+    }
+
+    pub fn rgb(self, hint: HintType) -> (u8, u8, u8) {
+        match (self, hint) {
+            (Palette::Standard, HintType::Correct) => (0x6a, 0xaa, 0x64),
+            (Palette::Standard, HintType::Present) => (0xc9, 0xb4, 0x58),
+            (Palette::HighContrast, HintType::Correct) => (0xf5, 0x79, 0x3a),
+            (Palette::HighContrast, HintType::Present) => (0x85, 0xc0, 0xf9),
+            (_, HintType::Absent) => (0x78, 0x7c, 0x7e),
+        }
+    }
+
+    pub fn emoji(self, hint: HintType) -> &'static str {
+        match (self, hint) {
+            (Palette::Standard, HintType::Correct) => "🟩",
+            (Palette::Standard, HintType::Present) => "🟨",
+            (Palette::HighContrast, HintType::Correct) => "🟧",
+            (Palette::HighContrast, HintType::Present) => "🟦",
+            (_, HintType::Absent) => "⬜",
+        }
+    }
+
+    /// Background and text color of a keyboard key. Keys not yet guessed are light gray
+    fn key_colors(self, status: Option<HintType>) -> ((u8, u8, u8), (u8, u8, u8)) {
+        match status {
+            Some(hint) => (self.rgb(hint), (0xff, 0xff, 0xff)),
+            None => ((0xd3, 0xd6, 0xda), (0x1a, 0x1a, 0x1b)),
+        }
+    }
+
+    fn key_emoji(self, status: Option<HintType>) -> &'static str {
+        match status {
+            Some(hint) => self.emoji(hint),
+            None => "🔲",
+        }
+    }
+}
+
+/// Draws single hints or whole games in one RenderStyle and Palette
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Renderer {
+    style: RenderStyle,
+    palette: Palette,
+}
+
+impl Renderer {
+    pub fn new(style: RenderStyle, palette: Palette) -> Self {
+        Renderer { style, palette }
+    }
+
+    /// Renderer from Python-facing options
+    pub fn from_options(style: &str, high_contrast: bool) -> PyResult<Self> {
+        Ok(Renderer::new(
+            RenderStyle::parse(style)?,
+            Palette::new(high_contrast),
+        ))
+    }
+
+    pub fn style(&self) -> RenderStyle {
+        self.style
+    }
+
+    pub fn render_hint(&self, hint: &WordleHint) -> String {
+        self.render_game(std::slice::from_ref(hint))
+    }
+
+    /// Render hints one row per guess. HTML output is a single grid for the whole game
+    pub fn render_game(&self, hints: &[WordleHint]) -> String {
+        match self.style {
+            RenderStyle::Emoji => {
+                let rows: Vec<String> = hints.iter().map(|hint| self.emoji_row(hint)).collect();
+                rows.join("\n")
+            }
+            RenderStyle::Ansi => {
+                let rows: Vec<String> = hints.iter().map(|hint| self.ansi_row(hint)).collect();
+                rows.join("\n")
+            }
+            RenderStyle::Html => self.html_grid(hints),
+        }
+    }
+
+    /// Render an on-screen keyboard with each key colored by its best known status
+    pub fn render_keyboard(&self, keyboard: &KeyboardState) -> String {
+        let rows = keyboard.rows();
+        match self.style {
+            RenderStyle::Emoji => {
+                let rows: Vec<String> = rows
+                    .iter()
+                    .map(|row| {
+                        let letters: Vec<String> = row
+                            .iter()
+                            .map(|(key, _)| key.to_uppercase().to_string())
+                            .collect();
+                        let squares: Vec<&str> = row
+                            .iter()
+                            .map(|(_, status)| self.palette.key_emoji(*status))
+                            .collect();
+                        format!("{}\n{}", letters.join(" "), squares.join(" "))
+                    })
+                    .collect();
+                rows.join("\n")
+            }
+            RenderStyle::Ansi => {
+                let rows: Vec<String> = rows
+                    .iter()
+                    .map(|row| {
+                        row.iter()
+                            .map(|(key, status)| {
+                                let ((r, g, b), (fr, fg, fb)) = self.palette.key_colors(*status);
+                                format!(
+                                    "\x1b[1;38;2;{};{};{};48;2;{};{};{}m {} \x1b[0m",
+                                    fr,
+                                    fg,
+                                    fb,
+                                    r,
+                                    g,
+                                    b,
+                                    key.to_uppercase()
+                                )
+                            })
+                            .collect::<Vec<String>>()
+                            .join(" ")
+                    })
+                    .collect();
+                rows.join("\n")
+            }
+            RenderStyle::Html => self.html_keyboard(&rows),
+        }
+    }
+
+    fn html_keyboard(&self, rows: &[Vec<(char, Option<HintType>)>]) -> String {
+        let rows: String = rows
+            .iter()
+            .map(|row| {
+                let keys: String = row
+                    .iter()
+                    .map(|(key, status)| {
+                        let ((r, g, b), (fr, fg, fb)) = self.palette.key_colors(*status);
+                        format!(
+                            "<div style=\"background:#{:02x}{:02x}{:02x};color:#{:02x}{:02x}{:02x};\
+                             font-weight:bold;min-width:1.8em;height:2.4em;border-radius:4px;\
+                             display:flex;align-items:center;justify-content:center\">{}</div>",
+                            r,
+                            g,
+                            b,
+                            fr,
+                            fg,
+                            fb,
+                            escape_html(&key.to_uppercase().to_string())
+                        )
+                    })
+                    .collect();
+                format!(
+                    "<div style=\"display:flex;justify-content:center;gap:4px\">{}</div>",
+                    keys
+                )
+            })
+            .collect();
+        format!(
+            "<div style=\"display:inline-flex;flex-direction:column;gap:4px;\
+             font-family:sans-serif\">{}</div>",
+            rows
+        )
+    }
+
+    fn emoji_row(&self, hint: &WordleHint) -> String {
+        let squares: Vec<&str> = hint
+            .hint_types()
+            .iter()
+            .map(|hint_type| self.palette.emoji(*hint_type))
+            .collect();
+        format!("{}\n{}", hint.spaced_letters(), squares.join(" "))
+    }
+
+    fn ansi_row(&self, hint: &WordleHint) -> String {
+        hint.word()
+            .chars()
+            .zip(hint.hint_types())
+            .map(|(letter, hint_type)| {
+                let (r, g, b) = self.palette.rgb(*hint_type);
+                format!(
+                    "\x1b[1;38;2;255;255;255;48;2;{};{};{}m {} \x1b[0m",
+                    r,
+                    g,
+                    b,
+                    letter.to_uppercase()
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+
+    fn html_grid(&self, hints: &[WordleHint]) -> String {
+        let num_columns = hints.first().map_or(0, |hint| hint.word_length());
+        let tiles: String = hints
+            .iter()
+            .flat_map(|hint| hint.word().chars().zip(hint.hint_types().iter()))
+            .map(|(letter, hint_type)| {
+                let (r, g, b) = self.palette.rgb(*hint_type);
+                format!(
+                    "<div style=\"background:#{:02x}{:02x}{:02x};color:#fff;font-weight:bold;\
+                     width:2.2em;height:2.2em;display:flex;align-items:center;\
+                     justify-content:center\">{}</div>",
+                    r,
+                    g,
+                    b,
+                    escape_html(&letter.to_uppercase().to_string())
+                )
+            })
+            .collect();
+        format!(
+            "<div style=\"display:inline-grid;grid-template-columns:repeat({}, 2.2em);gap:4px;\
+             font-family:sans-serif;font-size:1.2em\">{}</div>",
+            num_columns, tiles
+        )
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grade::grade_guess;
+
+    #[test]
+    fn test_emoji_palettes() {
+        let hint = grade_guess("crane", "cigar");
+        let standard = Renderer::new(RenderStyle::Emoji, Palette::Standard);
+        assert_eq!(standard.render_hint(&hint), "C R A N E\n🟩 🟨 🟨 ⬜ ⬜");
+        let high_contrast = Renderer::new(RenderStyle::Emoji, Palette::HighContrast);
+        assert_eq!(
+            high_contrast.render_hint(&hint),
+            "C R A N E\n🟧 🟦 🟦 ⬜ ⬜"
+        );
+    }
+
+    #[test]
+    fn test_ansi_and_html_tiles() {
+        let game = [grade_guess("crane", "cigar"), grade_guess("cigar", "cigar")];
+        let ansi = Renderer::new(RenderStyle::Ansi, Palette::Standard).render_game(&game);
+        assert_eq!(ansi.lines().count(), 2);
+        assert!(ansi.starts_with("\x1b[1;38;2;255;255;255;48;2;106;170;100m C \x1b[0m"));
+
+        let html = Renderer::new(RenderStyle::Html, Palette::HighContrast).render_game(&game);
+        assert!(html.contains("repeat(5, 2.2em)"));
+        assert_eq!(html.matches("#f5793a").count(), 6);
+        assert_eq!(html.matches("#85c0f9").count(), 2);
+    }
+
+    #[test]
+    fn test_keyboard() {
+        let keyboard = KeyboardState::from_hints(&[grade_guess("crane", "cigar")]).unwrap();
+        let emoji = Renderer::new(RenderStyle::Emoji, Palette::Standard).render_keyboard(&keyboard);
+        let lines: Vec<&str> = emoji.lines().collect();
+        assert_eq!(lines[0], "Q W E R T Y U I O P");
+        assert_eq!(lines[1], "🔲 🔲 ⬜ 🟨 🔲 🔲 🔲 🔲 🔲 🔲");
+        assert_eq!(lines.len(), 6);
+
+        let html = Renderer::new(RenderStyle::Html, Palette::Standard).render_keyboard(&keyboard);
+        assert_eq!(html.matches("#6aaa64").count(), 1);
+        assert_eq!(html.matches("#d3d6da").count(), 26 - 5);
+    }
+}
@@ -0,0 +1,258 @@
+use crate::utils::{fnv1a_hash, SplitMix64};
+use pyo3::prelude::*;
+use std::collections::{BTreeMap, HashMap};
+
+/// Share of the answer list held out from the train split
+pub const HOLDOUT_FRACTION: f64 = 0.2;
+
+/// Mixed into each word's hash when assigning splits, so they don't line up with other
+/// FNV-1a hashes of the same words
+const SPLIT_SALT: &str = "wordle-tournament-split:";
+
+/// Named subset of the answer list. Each word lands in train or holdout by a hash of the word
+/// alone, so the splits are the same for every team and don't shift when words are added
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Split {
+    All,
+    Train,
+    Holdout,
+}
+
+impl Split {
+    pub fn parse(split: &str) -> PyResult<Self> {
+        match split {
+            "all" => Ok(Split::All),
+            "train" => Ok(Split::Train),
+            "holdout" => Ok(Split::Holdout),
+            _ => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Unknown split '{}' - must be 'all', 'train' or 'holdout'",
+                split
+            ))),
+        }
+    }
+
+    pub fn contains(self, word: &str) -> bool {
+        match self {
+            Split::All => true,
+            Split::Train => !is_holdout(word),
+            Split::Holdout => is_holdout(word),
+        }
+    }
+}
+
+/// Whether a word belongs to the holdout split
+pub fn is_holdout(word: &str) -> bool {
+    let hash = fnv1a_hash(format!("{}{}", SPLIT_SALT, word).as_bytes());
+    (hash as f64) < HOLDOUT_FRACTION * u64::MAX as f64
+}
+
+/// How a sample is spread over the answer list
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strata {
+    /// Plain uniform sample
+    None,
+    /// Same share of words per first letter as the list
+    FirstLetter,
+    /// Same share of words per difficulty bucket as the list, see difficulty_buckets
+    Difficulty,
+}
+
+impl Strata {
+    pub fn parse(stratify: &str) -> PyResult<Self> {
+        match stratify {
+            "none" => Ok(Strata::None),
+            "first_letter" => Ok(Strata::FirstLetter),
+            "difficulty" => Ok(Strata::Difficulty),
+            _ => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Unknown stratify '{}' - must be 'none', 'first_letter' or 'difficulty'",
+                stratify
+            ))),
+        }
+    }
+}
+
+/// Difficulty bucket of each word: how many other words in the list differ from it in exactly
+/// one position, capped at 3. Words with many such neighbors (the _IGHT and _OUND families)
+/// are the ones that cost extra guesses
+pub fn difficulty_buckets(words: &[&str]) -> Vec<usize> {
+    let chars: Vec<Vec<char>> = words.iter().map(|word| word.chars().collect()).collect();
+    let mut patterns: HashMap<(usize, Vec<char>), usize> = HashMap::new();
+    for word in &chars {
+        for position in 0..word.len() {
+            let mut pattern = word.clone();
+            pattern[position] = '\0';
+            *patterns.entry((position, pattern)).or_default() += 1;
+        }
+    }
+    chars
+        .iter()
+        .map(|word| {
+            let neighbors: usize = (0..word.len())
+                .map(|position| {
+                    let mut pattern = word.clone();
+                    pattern[position] = '\0';
+                    patterns[&(position, pattern)] - 1
+                })
+                .sum();
+            neighbors.min(3)
+        })
+        .collect()
+}
+
+/// Which answers a local evaluation plays: a split of the list, then either its first words
+/// in list order (no seed) or a seeded, optionally stratified, random sample
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TargetSelection {
+    pub seed: Option<u64>,
+    pub strata: Strata,
+    pub split: Split,
+}
+
+impl Default for TargetSelection {
+    fn default() -> Self {
+        TargetSelection {
+            seed: None,
+            strata: Strata::None,
+            split: Split::All,
+        }
+    }
+}
+
+impl TargetSelection {
+    pub fn from_options(seed: Option<u64>, stratify: &str, split: &str) -> PyResult<Self> {
+        let selection = TargetSelection {
+            seed,
+            strata: Strata::parse(stratify)?,
+            split: Split::parse(split)?,
+        };
+        if selection.seed.is_none() && selection.strata != Strata::None {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "Stratified sampling needs a sample seed",
+            ));
+        }
+        Ok(selection)
+    }
+
+    /// Whether this is the plain file-order selection
+    pub fn is_default(&self) -> bool {
+        *self == TargetSelection::default()
+    }
+
+    /// Up to n words of the list, in list order
+    pub fn select<'a>(&self, words: &[&'a str], n: usize) -> Vec<&'a str> {
+        let words: Vec<&str> = words
+            .iter()
+            .copied()
+            .filter(|word| self.split.contains(word))
+            .collect();
+        match self.seed {
+            None => words.into_iter().take(n).collect(),
+            Some(seed) => {
+                let strata: Vec<usize> = match self.strata {
+                    Strata::None => vec![0; words.len()],
+                    Strata::FirstLetter => words
+                        .iter()
+                        .map(|word| word.chars().next().map_or(0, |c| c as usize))
+                        .collect(),
+                    Strata::Difficulty => difficulty_buckets(&words),
+                };
+                sample_stratified(&strata, n, seed)
+                    .into_iter()
+                    .map(|i| words[i])
+                    .collect()
+            }
+        }
+    }
+}
+
+/// Seeded sample of n of the indices, drawing from each stratum in proportion to its size
+/// (largest remainder rounding). Returned in increasing order
+pub fn sample_stratified(strata: &[usize], n: usize, seed: u64) -> Vec<usize> {
+    let n = n.min(strata.len());
+    let mut groups: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+    for (i, stratum) in strata.iter().enumerate() {
+        groups.entry(*stratum).or_default().push(i);
+    }
+
+    // Each stratum's exact share, floored, then the leftover picks go to the largest remainders
+    let total = strata.len();
+    let mut quotas: Vec<(usize, usize)> = groups
+        .values()
+        .map(|group| (group.len() * n / total, group.len() * n % total))
+        .collect();
+    let assigned: usize = quotas.iter().map(|(quota, _)| quota).sum();
+    let mut order: Vec<usize> = (0..quotas.len()).collect();
+    order.sort_by_key(|g| std::cmp::Reverse(quotas[*g].1));
+    for g in order.into_iter().take(n - assigned) {
+        quotas[g].0 += 1;
+    }
+
+    let mut rng = SplitMix64::new(seed);
+    let mut sample = vec![];
+    for (mut group, (quota, _)) in groups.into_values().zip(quotas) {
+        rng.shuffle(&mut group);
+        sample.extend_from_slice(&group[..quota]);
+    }
+    sample.sort_unstable();
+    sample
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::WORD_LENGTH;
+    use crate::corpus::Corpus;
+
+    #[test]
+    fn test_splits_partition_answers() {
+        let corpus = Corpus::embedded();
+        let answers = corpus.answers_of_length(WORD_LENGTH);
+        let holdout = answers.iter().filter(|word| is_holdout(word)).count();
+        let share = holdout as f64 / answers.len() as f64;
+        assert!((share - HOLDOUT_FRACTION).abs() < 0.03, "{}", share);
+        for word in answers.iter().take(50) {
+            assert_ne!(Split::Train.contains(word), Split::Holdout.contains(word));
+        }
+    }
+
+    #[test]
+    fn test_seeded_sample_is_reproducible() {
+        let corpus = Corpus::embedded();
+        let answers = &corpus.answers_of_length(WORD_LENGTH);
+        let selection = TargetSelection {
+            seed: Some(7),
+            ..TargetSelection::default()
+        };
+        let sample = selection.select(answers, 100);
+        assert_eq!(sample.len(), 100);
+        assert_eq!(sample, selection.select(answers, 100));
+        let other = TargetSelection {
+            seed: Some(8),
+            ..TargetSelection::default()
+        };
+        assert_ne!(sample, other.select(answers, 100));
+        // Not just the start of the alphabet
+        assert!(sample.iter().any(|word| word.starts_with('w')));
+
+        let first = TargetSelection::default().select(answers, 3);
+        assert_eq!(first, answers[..3]);
+    }
+
+    #[test]
+    fn test_stratified_sample_keeps_proportions() {
+        let strata = [[0; 60], [1; 60], [2; 60]].concat();
+        let sample = sample_stratified(&[strata.as_slice(), &[3; 120]].concat(), 30, 1);
+        assert_eq!(sample.len(), 30);
+        let in_last = sample.iter().filter(|i| **i >= 180).count();
+        assert_eq!(in_last, 12);
+
+        assert_eq!(sample_stratified(&[0, 0, 1], 5, 1), [0, 1, 2]);
+    }
+
+    #[test]
+    fn test_difficulty_counts_one_letter_neighbors() {
+        let words = ["light", "might", "night", "sight", "tight", "crane"];
+        assert_eq!(difficulty_buckets(&words), [3, 3, 3, 3, 3, 0]);
+        assert_eq!(difficulty_buckets(&["cigar", "cigars"]), [0, 0]);
+    }
+}
@@ -0,0 +1,244 @@
+use crate::common::{MAX_GUESSES, WORD_LENGTH};
+use crate::game_record::GameRecord;
+use crate::grade::{grade_code, grade_guess};
+use crate::hint::{decode_pattern, HintFormat, HintType, NUM_PATTERNS};
+use crate::normalize::word_length;
+use crate::render::Palette;
+use pyo3::prelude::*;
+use std::collections::HashSet;
+use std::fmt;
+
+/// "Wordle 3/20" share text: a header with the number of guesses (X if unsolved, * for hard
+/// mode) followed by the emoji grid, without any letters
+pub fn share_text(record: &GameRecord, palette: Palette) -> String {
+    let score = match record.solved() {
+        true => record.num_guesses().to_string(),
+        false => "X".to_string(),
+    };
+    let hard = match record.is_hard_mode() {
+        true => "*",
+        false => "",
+    };
+    let rows: Vec<String> = record
+        .hints()
+        .iter()
+        .map(|hint| {
+            hint.hint_types()
+                .iter()
+                .map(|hint_type| palette.emoji(*hint_type))
+                .collect()
+        })
+        .collect();
+    format!(
+        "Wordle {}/{}{}\n\n{}",
+        score,
+        MAX_GUESSES,
+        hard,
+        rows.join("\n")
+    )
+}
+
+/// Why a pasted share grid could not be read
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShareParseError {
+    NoRows,
+    /// 1-based row whose length differs from the first row
+    RaggedRow {
+        row: usize,
+        length: usize,
+        expected: usize,
+    },
+    /// 1-based all-green row that isn't the last one, though the game ends once it's solved
+    SolvedBeforeLastRow {
+        row: usize,
+    },
+}
+
+impl fmt::Display for ShareParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ShareParseError::NoRows => write!(f, "Share text contains no rows of emoji squares"),
+            ShareParseError::RaggedRow {
+                row,
+                length,
+                expected,
+            } => write!(
+                f,
+                "Row {} of the share grid has {} squares, expected {}",
+                row, length, expected
+            ),
+            ShareParseError::SolvedBeforeLastRow { row } => write!(
+                f,
+                "Row {} of the share grid is all green, but it isn't the last row",
+                row
+            ),
+        }
+    }
+}
+
+fn is_square(c: char) -> bool {
+    matches!(c, '🟩' | '🟨' | '⬜' | '⬛' | '🟧' | '🟦')
+}
+
+/// Read the emoji rows of pasted share text into a pattern-only history. Lines that aren't
+/// made of emoji squares, like the "Wordle 1,234 3/6" header, are skipped. Only the last
+/// row may be all green
+pub fn parse_share_grid(text: &str) -> Result<Vec<Vec<HintType>>, ShareParseError> {
+    let mut rows: Vec<Vec<HintType>> = vec![];
+    for line in text.lines() {
+        let squares: Vec<char> = line
+            .chars()
+            .filter(|c| !c.is_whitespace() && *c != '\u{FE0F}')
+            .collect();
+        if squares.is_empty() || !squares.iter().all(|c| is_square(*c)) {
+            continue;
+        }
+        let row: Vec<HintType> = squares
+            .into_iter()
+            .filter_map(HintType::from_char)
+            .collect();
+        if let Some(last) = rows.last() {
+            if last.iter().all(|hint_type| *hint_type == HintType::Correct) {
+                return Err(ShareParseError::SolvedBeforeLastRow { row: rows.len() });
+            }
+        }
+        if let Some(first) = rows.first() {
+            if row.len() != first.len() {
+                return Err(ShareParseError::RaggedRow {
+                    row: rows.len() + 1,
+                    length: row.len(),
+                    expected: first.len(),
+                });
+            }
+        }
+        rows.push(row);
+    }
+    match rows.is_empty() {
+        true => Err(ShareParseError::NoRows),
+        false => Ok(rows),
+    }
+}
+
+/// Parse pasted share text into a pattern-only history, one 'O~X' string per row
+#[pyfunction(name = "parse_share_grid")]
+pub fn parse_share_grid_py(text: &str) -> PyResult<Vec<String>> {
+    let rows = parse_share_grid(text)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+    Ok(rows
+        .iter()
+        .map(|row| HintFormat::Symbols.format(row))
+        .collect())
+}
+
+/// Answers for which every row of a pattern-only history could have come from some word in
+/// `guesses`. Rows are checked independently, since the guessed words are unknown
+pub fn answers_fitting_grid<'a>(
+    rows: &[Vec<HintType>],
+    answers: &[&'a str],
+    guesses: &[&str],
+) -> Vec<&'a str> {
+    let Some(first) = rows.first() else {
+        return answers.to_vec();
+    };
+    let num_letters = first.len();
+    let guesses: Vec<&str> = guesses
+        .iter()
+        .copied()
+        .filter(|guess| word_length(guess) == num_letters)
+        .collect();
+
+    answers
+        .iter()
+        .copied()
+        .filter(|answer| word_length(answer) == num_letters)
+        .filter(|answer| {
+            let reachable = reachable_patterns(answer, &guesses);
+            rows.iter().all(|row| reachable.contains(row))
+        })
+        .collect()
+}
+
+/// Every pattern some guess produces against this answer
+fn reachable_patterns(answer: &str, guesses: &[&str]) -> HashSet<Vec<HintType>> {
+    // Fast path for standard ASCII words: collect pattern codes, then expand the few seen
+    if let Ok(answer_bytes) = <&[u8; WORD_LENGTH]>::try_from(answer.as_bytes()) {
+        if answer.is_ascii() && guesses.iter().all(|guess| guess.is_ascii()) {
+            let mut seen = [false; NUM_PATTERNS];
+            for guess in guesses {
+                if let Ok(guess_bytes) = <&[u8; WORD_LENGTH]>::try_from(guess.as_bytes()) {
+                    seen[grade_code(guess_bytes, answer_bytes) as usize] = true;
+                }
+            }
+            return (0..NUM_PATTERNS)
+                .filter(|code| seen[*code])
+                .map(|code| decode_pattern(code as u8).to_vec())
+                .collect();
+        }
+    }
+    guesses
+        .iter()
+        .map(|guess| grade_guess(guess, answer).hint_types().to_vec())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_record::GameOutcome;
+
+    #[test]
+    fn test_share_text_round_trip() {
+        let hints = vec![grade_guess("crane", "cigar"), grade_guess("cigar", "cigar")];
+        let record = GameRecord::new(
+            Some("cigar".to_string()),
+            hints.clone(),
+            GameOutcome::Solved,
+            "standard".to_string(),
+            true,
+        );
+        let text = share_text(&record, Palette::Standard);
+        assert_eq!(
+            text,
+            format!("Wordle 2/{}*\n\n🟩🟨🟨⬜⬜\n🟩🟩🟩🟩🟩", MAX_GUESSES)
+        );
+
+        let rows = parse_share_grid(&text).unwrap();
+        let expected: Vec<Vec<HintType>> = hints.iter().map(|h| h.hint_types().to_vec()).collect();
+        assert_eq!(rows, expected);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            parse_share_grid("Wordle 1,234 3/6"),
+            Err(ShareParseError::NoRows)
+        );
+        assert_eq!(
+            parse_share_grid("🟩🟨⬛⬛⬛\n🟩🟩🟩🟩"),
+            Err(ShareParseError::RaggedRow {
+                row: 2,
+                length: 4,
+                expected: 5
+            })
+        );
+        assert_eq!(
+            parse_share_grid("🟩🟨⬛⬛⬛\n🟩🟩🟩🟩🟩\n🟩🟩🟩🟩🟩"),
+            Err(ShareParseError::SolvedBeforeLastRow { row: 2 })
+        );
+    }
+
+    #[test]
+    fn test_answers_fitting_grid() {
+        let guesses = ["crane", "robot", "cigar", "civic", "rotor"];
+        let answers = ["cigar", "civic", "robot", "rotor"];
+        // A 🟩🟨🟨⬜⬜ row can come from crane vs cigar, but from no guess vs the others
+        let rows = parse_share_grid("🟩🟨🟨⬜⬜\n🟩🟩🟩🟩🟩").unwrap();
+        assert_eq!(answers_fitting_grid(&rows, &answers, &guesses), ["cigar"]);
+
+        let all_correct = vec![vec![HintType::Correct; 5]];
+        assert_eq!(
+            answers_fitting_grid(&all_correct, &answers, &guesses),
+            answers
+        );
+    }
+}
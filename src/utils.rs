@@ -8,3 +8,99 @@ pub fn py_print(py: Python, msg: &str) -> PyResult<()> {
     print.call1((msg,))?;
     Ok(())
 }
+
+/// Show HTML as rich output when running under IPython/Jupyter, printing the raw markup otherwise
+pub fn py_display_html(py: Python, html: &str) -> PyResult<()> {
+    let in_ipython = match PyModule::import(py, "IPython") {
+        Ok(ipython) => !ipython.getattr("get_ipython")?.call0()?.is_none(),
+        Err(_) => false,
+    };
+    if !in_ipython {
+        return py_print(py, html);
+    }
+    let display = PyModule::import(py, "IPython.display")?;
+    let html = display.getattr("HTML")?.call1((html,))?;
+    display.getattr("display")?.call1((html,))?;
+    Ok(())
+}
+
+/// 64-bit FNV-1a hash. Used for content hashes that get written to disk, so unlike
+/// std's DefaultHasher the result must never change between Rust versions
+pub fn fnv1a_hash(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Small seeded SplitMix64 generator. Used instead of an external RNG so that a given
+/// seed reproduces the same run on every platform and library version
+#[derive(Debug, Clone)]
+pub struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    pub fn new(seed: u64) -> Self {
+        SplitMix64 { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform integer in 0..bound. Panics if bound is 0
+    pub fn below(&mut self, bound: usize) -> usize {
+        assert!(bound > 0, "bound must be positive");
+        // Rejection sampling to avoid modulo bias
+        let bound = bound as u64;
+        let zone = u64::MAX - u64::MAX % bound;
+        loop {
+            let value = self.next_u64();
+            if value < zone {
+                return (value % bound) as usize;
+            }
+        }
+    }
+
+    /// Uniform float in [0, 1)
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Shuffle a slice in place (Fisher-Yates)
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fnv1a_known_values() {
+        assert_eq!(fnv1a_hash(b""), 0xcbf29ce484222325);
+        assert_eq!(fnv1a_hash(b"a"), 0xaf63dc4c8601ec8c);
+    }
+
+    #[test]
+    fn test_split_mix_is_reproducible() {
+        let mut a = SplitMix64::new(42);
+        let mut b = SplitMix64::new(42);
+        let draws: Vec<usize> = (0..100).map(|_| a.below(7)).collect();
+        assert_eq!(draws, (0..100).map(|_| b.below(7)).collect::<Vec<_>>());
+        assert!(draws.iter().all(|d| *d < 7));
+        assert_ne!(SplitMix64::new(1).next_u64(), SplitMix64::new(2).next_u64());
+
+        let mut items: Vec<usize> = (0..10).collect();
+        SplitMix64::new(7).shuffle(&mut items);
+        items.sort();
+        assert_eq!(items, (0..10).collect::<Vec<_>>());
+    }
+}
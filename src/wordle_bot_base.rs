@@ -1,135 +1,906 @@
+use crate::adversary::AdversarialGrader;
 use crate::backend_client;
-use crate::common::{DUMMY_GUESS, MAX_GUESSES, NUM_TARGET_WORDS};
-use crate::corpus::{get_grading_answer_key, is_valid_word};
-use crate::grade::grade_guess;
+use crate::common::{
+    DUMMY_GUESS, MAX_BOARDS, MAX_GUESSES, MAX_WORD_LENGTH, MIN_WORD_LENGTH, NUM_TARGET_WORDS,
+    WORD_LENGTH,
+};
+use crate::consistency::{check_history, retain_consistent, HistoryCheck};
+use crate::corpus::Corpus;
+use crate::count_hint::CountHint;
+use crate::fibble::LyingGrader;
+use crate::frequency::FrequencyTable;
+use crate::game_record::{GameOutcome, GameRecord};
+use crate::game_state::{GameState, GuessStyle};
+use crate::grade::{grade_counts, grade_guess, FixedAnswerGrader, Grader};
+use crate::hard_mode::check_hard_mode;
 use crate::hint::{HintType, WordleHint};
-use crate::utils::py_print;
+use crate::history::GuessHistory;
+use crate::keyboard::KeyboardState;
+use crate::multi_board::{is_valid_num_boards, max_multi_board_guesses, MultiBoardGame};
+use crate::normalize;
+use crate::render::{RenderStyle, Renderer};
+use crate::sampling::TargetSelection;
+use crate::share::{answers_fitting_grid, parse_share_grid};
+use crate::utils::{py_display_html, py_print, SplitMix64};
 use pyo3::exceptions::PyNotImplementedError;
 use pyo3::prelude::*;
 use pyo3::types::PyList;
 use pyo3::Bound;
+use std::collections::HashSet;
+
+/// How guesses are graded during local evaluation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GradingMode {
+    /// Grade against a fixed answer from the answer key
+    Standard,
+    /// Absurdle-style: no answer is picked up front, see AdversarialGrader
+    Adversarial,
+    /// Fibble-style: a fixed number of tiles per row are lies, see LyingGrader
+    Fibble,
+}
+
+impl GradingMode {
+    fn parse(grading_mode: &str) -> PyResult<Self> {
+        match grading_mode {
+            "standard" => Ok(GradingMode::Standard),
+            "adversarial" => Ok(GradingMode::Adversarial),
+            "fibble" => Ok(GradingMode::Fibble),
+            _ => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Unknown grading mode '{}' - must be 'standard', 'adversarial' or 'fibble'",
+                grading_mode
+            ))),
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            GradingMode::Standard => "standard",
+            GradingMode::Adversarial => "adversarial",
+            GradingMode::Fibble => "fibble",
+        }
+    }
+}
+
+/// Where a tournament round's guesses are graded
+enum RoundGrader<'a> {
+    /// Locally against the answer key, one answer per target
+    AnswerKey(&'a [&'a str]),
+    /// Locally by one stateful grader per target
+    PerTarget(&'a mut [LyingGrader]),
+    /// By the tournament server
+    Server,
+}
+
+/// The per-target games of one tournament run, played a round at a time by play_rounds.
+/// Each round takes one guess per target slot and hands back one hint per slot
+trait TournamentGames {
+    /// This round's guess for every target slot, DUMMY_GUESS for slots that are done
+    fn next_guesses(&mut self, slf: &Bound<'_, UChicagoWordleBotBase>) -> PyResult<Vec<String>>;
+
+    /// Take the hints for this round's guesses, in target slot order
+    fn record_hints(&mut self, guesses: &[String], hints: Vec<WordleHint>) -> PyResult<()>;
+}
+
+/// Index of the guess that solved a game. When the answer is known the guess itself decides,
+/// so a lying fibble hint can't end the game; otherwise the first fully correct hint does
+fn solved_at(hints: &[WordleHint], answer: Option<&str>) -> Option<usize> {
+    hints.iter().position(|hint| match answer {
+        Some(answer) => hint.word() == answer,
+        None => hint.is_fully_correct(),
+    })
+}
+
+/// One guess() game per target, as played by evaluate()
+struct HintGames<'a> {
+    histories: Vec<GuessHistory>,
+    style: GuessStyle,
+    hard_mode: bool,
+    /// Each target's answer under local grading, used to tell when it is solved. Empty under
+    /// remote grading, where the server's fully correct hint decides
+    answers: &'a [&'a str],
+    /// Answers each target could still be, used to catch server hints that no answer fits.
+    /// Empty unless remote evaluation was asked to verify the server's hints
+    server_candidates: Vec<Vec<&'a str>>,
+}
+
+impl TournamentGames for HintGames<'_> {
+    fn next_guesses(&mut self, slf: &Bound<'_, UChicagoWordleBotBase>) -> PyResult<Vec<String>> {
+        let mut guesses = vec![];
+        for (i, history) in self.histories.iter().enumerate() {
+            // Skip calling guess() if they've already guessed the word
+            if solved_at(history.hints(), self.answers.get(i).copied()).is_some() {
+                guesses.push(DUMMY_GUESS.to_string());
+                continue;
+            }
+
+            let guess =
+                UChicagoWordleBotBase::call_guess(slf, self.style, history, self.hard_mode)?;
+            if self.hard_mode {
+                UChicagoWordleBotBase::validate_hard_mode(&guess, history.hints())?;
+            }
+            guesses.push(guess);
+        }
+        Ok(guesses)
+    }
+
+    fn record_hints(&mut self, guesses: &[String], hints: Vec<WordleHint>) -> PyResult<()> {
+        if !self.server_candidates.is_empty() {
+            UChicagoWordleBotBase::check_server_hints(
+                &mut self.server_candidates,
+                guesses,
+                &hints,
+            )?;
+        }
+        for (history, hint) in self.histories.iter_mut().zip(hints) {
+            history.push(hint);
+        }
+        Ok(())
+    }
+}
+
+/// One guess_boards() game per group of num_boards targets, as played by evaluate_boards().
+/// Target slot game * num_boards + board holds that board of that game
+struct BoardGames {
+    games: Vec<MultiBoardGame>,
+    num_boards: usize,
+    style: GuessStyle,
+}
+
+impl TournamentGames for BoardGames {
+    fn next_guesses(&mut self, slf: &Bound<'_, UChicagoWordleBotBase>) -> PyResult<Vec<String>> {
+        // Closed boards and cleared games get the dummy guess
+        let mut guesses = vec![DUMMY_GUESS.to_string(); self.games.len() * self.num_boards];
+        for (game_idx, game) in self.games.iter().enumerate() {
+            if game.is_cleared() {
+                continue;
+            }
+            let guess = UChicagoWordleBotBase::call_guess_boards(slf, self.style, game)?;
+            for board in game.open_boards() {
+                guesses[game_idx * self.num_boards + board] = guess.clone();
+            }
+        }
+        Ok(guesses)
+    }
+
+    fn record_hints(&mut self, _guesses: &[String], hints: Vec<WordleHint>) -> PyResult<()> {
+        for (game_idx, game) in self.games.iter_mut().enumerate() {
+            if game.is_cleared() {
+                continue;
+            }
+            let hints = game
+                .open_boards()
+                .into_iter()
+                .map(|board| hints[game_idx * self.num_boards + board].clone())
+                .collect();
+            game.record_guess(hints);
+        }
+        Ok(())
+    }
+}
+
+/// One guess_counts() game per target, as played by evaluate_counts()
+struct CountGames {
+    histories: Vec<Vec<CountHint>>,
+}
+
+impl TournamentGames for CountGames {
+    fn next_guesses(&mut self, slf: &Bound<'_, UChicagoWordleBotBase>) -> PyResult<Vec<String>> {
+        let mut guesses = vec![];
+        for history in self.histories.iter() {
+            // Skip calling guess_counts() if they've already guessed the word
+            if history.last().is_some_and(|hint| hint.is_fully_correct()) {
+                guesses.push(DUMMY_GUESS.to_string());
+                continue;
+            }
+            guesses.push(UChicagoWordleBotBase::call_guess_counts(slf, history)?);
+        }
+        Ok(guesses)
+    }
+
+    fn record_hints(&mut self, _guesses: &[String], hints: Vec<WordleHint>) -> PyResult<()> {
+        for (history, hint) in self.histories.iter_mut().zip(hints.iter()) {
+            if !history.last().is_some_and(|hint| hint.is_fully_correct()) {
+                history.push(CountHint::from_hint(hint));
+            }
+        }
+        Ok(())
+    }
+}
 
 #[pyclass(subclass)]
 pub struct UChicagoWordleBotBase {
     #[pyo3(get, set)]
     team_id: String,
+    #[pyo3(get)]
+    word_length: usize,
+    corpus: Corpus,
+    /// Games played by the most recent evaluate/evaluate_on_word call
+    last_games: Vec<GameRecord>,
 }
 
 #[pymethods]
 impl UChicagoWordleBotBase {
     #[new]
-    pub fn new(team_id: String) -> Self {
-        UChicagoWordleBotBase { team_id }
+    #[pyo3(signature = (team_id, word_length = WORD_LENGTH, corpus = None))]
+    pub fn new(team_id: String, word_length: usize, corpus: Option<Corpus>) -> PyResult<Self> {
+        let mut bot = UChicagoWordleBotBase {
+            team_id,
+            word_length: WORD_LENGTH,
+            corpus: corpus.unwrap_or_else(Corpus::embedded),
+            last_games: vec![],
+        };
+        bot.set_word_length(word_length)?;
+        Ok(bot)
     }
 
-    /// Python exposed method to grade user's guess() function on a single word
-    pub fn evaluate_on_word(slf: Bound<'_, Self>, answer: String, logging: bool) -> PyResult<i64> {
+    /// Word length of the tournament this bot is evaluated in (5 by default)
+    #[setter]
+    pub fn set_word_length(&mut self, word_length: usize) -> PyResult<()> {
+        if !(MIN_WORD_LENGTH..=MAX_WORD_LENGTH).contains(&word_length) {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Word length must be between {} and {}, got {}",
+                MIN_WORD_LENGTH, MAX_WORD_LENGTH, word_length
+            )));
+        }
+        self.word_length = word_length;
+        Ok(())
+    }
+
+    /// Word lists guesses are validated and graded against (the embedded English lists by
+    /// default)
+    #[getter]
+    pub fn corpus(&self) -> Corpus {
+        self.corpus.clone()
+    }
+
+    #[setter]
+    pub fn set_corpus(&mut self, corpus: Corpus) {
+        self.corpus = corpus;
+    }
+
+    /// Play with word lists loaded from UTF-8 files (one word per line) instead of the
+    /// embedded English lists. Shorthand for setting corpus to Corpus.from_files(...)
+    #[pyo3(signature = (guesses_path, answers_path, strip_accents = false, keep_accented = ""))]
+    pub fn use_word_lists(
+        &mut self,
+        guesses_path: &str,
+        answers_path: &str,
+        strip_accents: bool,
+        keep_accented: &str,
+    ) -> PyResult<()> {
+        self.corpus =
+            Corpus::from_files_py(guesses_path, answers_path, strip_accents, keep_accented)?;
+        Ok(())
+    }
+
+    /// Go back to the embedded English word lists
+    pub fn use_embedded_word_lists(&mut self) {
+        self.corpus = Corpus::embedded();
+    }
+
+    /// Check whether some word in the answer list (or the whole guess corpus, with
+    /// use_corpus) produces exactly these hints, naming the first hint that rules out every word
+    #[pyo3(signature = (hints, use_corpus = false))]
+    pub fn check_hint_history(&self, hints: Vec<WordleHint>, use_corpus: bool) -> HistoryCheck {
+        let words = match use_corpus {
+            true => self.corpus.guesses_of_length(self.word_length),
+            false => self.corpus.answers_of_length(self.word_length),
+        };
+        check_history(&hints, &words)
+    }
+
+    /// Words from the answer list (or the whole guess corpus, with use_corpus) that could
+    /// still be the answer given these hints, in list order
+    #[pyo3(signature = (hints, use_corpus = false))]
+    pub fn remaining_words(
+        &self,
+        hints: Vec<WordleHint>,
+        use_corpus: bool,
+    ) -> PyResult<Vec<String>> {
+        let words = self.word_list(use_corpus);
+        Ok(self
+            .remaining_indices(hints, use_corpus)?
+            .into_iter()
+            .map(|i| words[i].clone())
+            .collect())
+    }
+
+    /// Same as remaining_words, but as indices into answer_list (or guess_list, with use_corpus)
+    #[pyo3(signature = (hints, use_corpus = false))]
+    pub fn remaining_indices(
+        &self,
+        hints: Vec<WordleHint>,
+        use_corpus: bool,
+    ) -> PyResult<Vec<usize>> {
+        Ok(self
+            .corpus
+            .letter_index(use_corpus)
+            .candidates(&hints, self.word_length)?
+            .iter()
+            .collect())
+    }
+
+    /// Guess list words that are legal hard-mode guesses after these hints: every revealed
+    /// green reused in place and every revealed letter reused, in list order
+    pub fn hard_mode_guesses(&self, hints: Vec<WordleHint>) -> Vec<String> {
+        let guesses = self.corpus.guesses();
+        self.corpus
+            .letter_index(true)
+            .hard_mode_guesses(&hints, self.word_length)
+            .iter()
+            .map(|i| guesses[i].clone())
+            .collect()
+    }
+
+    /// Prior weights from a frequency table, aligned with answer_list (or guess_list, with
+    /// use_corpus) and summing to 1. smoothing is 'raw', 'log' or 'sigmoid'; see
+    /// FrequencyTable.priors
+    #[pyo3(signature = (table, use_corpus = false, smoothing = "raw", threshold = None, width = 0.5))]
+    pub fn prior_weights(
+        &self,
+        table: &FrequencyTable,
+        use_corpus: bool,
+        smoothing: &str,
+        threshold: Option<f64>,
+        width: f64,
+    ) -> PyResult<Vec<f64>> {
+        table.for_corpus(&self.corpus).priors_with_options(
+            self.word_list(use_corpus),
+            smoothing,
+            threshold,
+            width,
+        )
+    }
+
+    /// Records of the games played by the most recent evaluate() or evaluate_on_word() call,
+    /// one per target word, ready to be saved with save_game_records()
+    #[getter]
+    pub fn last_game_records(&self) -> Vec<GameRecord> {
+        self.last_games.clone()
+    }
+
+    /// Answers (or corpus words, with use_corpus) that a pasted share grid could have been
+    /// played against: every row must be producible by some valid guess, which includes every
+    /// answer
+    #[pyo3(signature = (share_text, use_corpus = false))]
+    pub fn answers_fitting_share_grid(
+        &self,
+        share_text: &str,
+        use_corpus: bool,
+    ) -> PyResult<Vec<String>> {
+        let rows = parse_share_grid(share_text)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+        // Every answer is a valid guess, even if it's missing from the guess list
+        let mut seen = HashSet::new();
+        let guesses: Vec<&str> = self
+            .corpus
+            .guesses()
+            .iter()
+            .chain(self.corpus.answers())
+            .map(|w| w.as_str())
+            .filter(|w| seen.insert(*w))
+            .collect();
+        let answers: Vec<&str> = self
+            .word_list(use_corpus)
+            .iter()
+            .map(|w| w.as_str())
+            .collect();
+        Ok(answers_fitting_grid(&rows, &answers, &guesses)
+            .into_iter()
+            .map(str::to_string)
+            .collect())
+    }
+
+    /// Answers of the bot's word length that evaluate(grade_local=True) would play with these
+    /// options: the train or holdout split (or all answers), then a seeded sample of n, or the
+    /// first n in file order without a seed. stratify ('none', 'first_letter' or 'difficulty')
+    /// keeps each first letter's or difficulty bucket's share of the list
+    #[pyo3(signature = (n = NUM_TARGET_WORDS, seed = None, stratify = "none", split = "all"))]
+    pub fn sample_answers(
+        &self,
+        n: usize,
+        seed: Option<u64>,
+        stratify: &str,
+        split: &str,
+    ) -> PyResult<Vec<String>> {
+        let selection = TargetSelection::from_options(seed, stratify, split)?;
+        Ok(selection
+            .select(&self.corpus.answers_of_length(self.word_length), n)
+            .into_iter()
+            .map(str::to_string)
+            .collect())
+    }
+
+    /// Every word in the guess list, in file order. Answers missing from it are valid guesses
+    /// too; use corpus.is_valid_word to check a word
+    #[getter]
+    pub fn guess_list(&self) -> Vec<String> {
+        self.corpus.guesses().to_vec()
+    }
+
+    /// Every word in the answer list, in file order
+    #[getter]
+    pub fn answer_list(&self) -> Vec<String> {
+        self.corpus.answers().to_vec()
+    }
+
+    /// Python exposed method to grade user's guess() function on a single word.
+    /// In hard mode every guess must reuse all previously revealed greens and yellows.
+    /// In adversarial grading mode no answer is fixed, so `answer` only sets the word length.
+    /// In fibble grading mode lies_per_row tiles of each row are lies, chosen from `seed`.
+    /// With logging, hints are drawn in `style` ('emoji', 'ansi' or 'html')
+    #[pyo3(signature = (
+        answer,
+        logging = true,
+        hard_mode = false,
+        grading_mode = "standard",
+        lies_per_row = 1,
+        seed = 0,
+        style = "emoji",
+        high_contrast = false
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn evaluate_on_word(
+        slf: Bound<'_, Self>,
+        answer: String,
+        logging: bool,
+        hard_mode: bool,
+        grading_mode: &str,
+        lies_per_row: usize,
+        seed: u64,
+        style: &str,
+        high_contrast: bool,
+    ) -> PyResult<i64> {
         let py = slf.py();
+        let word_length = slf.borrow().word_length;
+        let grading_mode = GradingMode::parse(grading_mode)?;
+        let renderer = Renderer::from_options(style, high_contrast)?;
+        let renderer = logging.then_some(renderer);
+        Self::validate_grading_options(grading_mode, true, hard_mode, lies_per_row, word_length)?;
+        let answer = slf.borrow().corpus.normalize(&answer);
 
-        if logging {
-            py_print(py, &format!("Evaluating bot on answer: {}", answer))?;
-            py_print(py, "----------------------------------------------------")?;
+        if normalize::word_length(&answer) != word_length {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Answer {} is not {} letters long",
+                answer, word_length
+            )));
         }
 
-        let hint_list = PyList::empty(py);
-        let mut guesses = vec![];
-
-        for num_guesses in 1..=MAX_GUESSES {
-            let guess: String = slf.call_method1("guess", (&hint_list,))?.extract()?;
-            if !is_valid_word(&guess) {
-                return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
-                    "Guess {} is not a valid word - must be in corpus",
-                    guess
-                )));
+        let num_guesses = match grading_mode {
+            GradingMode::Standard => {
+                if logging {
+                    py_print(py, &format!("Evaluating bot on answer: {}", answer))?;
+                    py_print(py, "----------------------------------------------------")?;
+                }
+                let mut grader = FixedAnswerGrader::new(answer.clone());
+                Self::play_game(
+                    &slf,
+                    &mut grader,
+                    Some(&answer),
+                    grading_mode,
+                    hard_mode,
+                    renderer,
+                )?
             }
-            guesses.push(guess.clone());
-            let hint = grade_guess(&guess, &answer);
-            if logging {
-                hint.visualize_hint(py)?;
+            GradingMode::Adversarial => {
+                if logging {
+                    py_print(py, "Evaluating bot against adversarial grader")?;
+                    py_print(py, "----------------------------------------------------")?;
+                }
+                let (num_guesses, grader) = Self::play_adversarial_game(&slf, hard_mode, renderer)?;
+                if logging {
+                    Self::report_adversarial_game(py, num_guesses, &grader)?;
+                }
+                num_guesses
             }
-            if hint.is_fully_correct() {
-                return Ok(num_guesses as i64);
+            GradingMode::Fibble => {
+                if logging {
+                    py_print(
+                        py,
+                        &format!(
+                            "Evaluating bot on answer: {} (fibble, {} lies per row, seed {})",
+                            answer, lies_per_row, seed
+                        ),
+                    )?;
+                    py_print(py, "----------------------------------------------------")?;
+                }
+                let mut grader = LyingGrader::new(answer.clone(), lies_per_row, seed);
+                let result = Self::play_game(
+                    &slf,
+                    &mut grader,
+                    Some(&answer),
+                    grading_mode,
+                    hard_mode,
+                    renderer,
+                );
+                {
+                    let mut bot = slf.borrow_mut();
+                    if let Some(record) = bot.last_games.pop() {
+                        bot.last_games
+                            .push(record.with_lies(grader.lies().to_vec()));
+                    }
+                }
+                if logging {
+                    Self::report_lies(py, &grader)?;
+                }
+                result?
             }
-            hint_list.append(Py::new(py, hint)?)?;
-        }
+        };
 
-        Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
-            "Failed to guess answer in {} guesses",
-            MAX_GUESSES
-        )))
+        Ok(num_guesses as i64)
     }
 
     /// The big daddy method that runs tournament evaluation, either locally (only
     /// avg guess calculation) or remotely (avg guess calculation and server grading)
-    pub fn evaluate(slf: Bound<'_, Self>, grade_local: bool) -> PyResult<f64> {
+    #[pyo3(signature = (
+        grade_local,
+        hard_mode = false,
+        grading_mode = "standard",
+        lies_per_row = 1,
+        seed = 0,
+        sample_seed = None,
+        stratify = "none",
+        split = "all",
+        verify_server_hints = false
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn evaluate(
+        slf: Bound<'_, Self>,
+        grade_local: bool,
+        hard_mode: bool,
+        grading_mode: &str,
+        lies_per_row: usize,
+        seed: u64,
+        sample_seed: Option<u64>,
+        stratify: &str,
+        split: &str,
+        verify_server_hints: bool,
+    ) -> PyResult<f64> {
         let py = slf.py();
-        let team_id: &str = &slf.borrow().team_id;
+        let team_id = slf.borrow().team_id.clone();
+        let team_id: &str = &team_id;
+        let word_length = slf.borrow().word_length;
+        let grading_mode = GradingMode::parse(grading_mode)?;
+        Self::validate_grading_options(
+            grading_mode,
+            grade_local,
+            hard_mode,
+            lies_per_row,
+            word_length,
+        )?;
+
+        if grading_mode == GradingMode::Adversarial {
+            return Self::evaluate_adversarial(&slf, hard_mode);
+        }
+
+        let selection = TargetSelection::from_options(sample_seed, stratify, split)?;
+        if !grade_local && !selection.is_default() {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "Answer sampling and splits are only available with grade_local=True",
+            ));
+        }
+        if grade_local && verify_server_hints {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "verify_server_hints only applies with grade_local=False",
+            ));
+        }
+
+        // Local grading plays the selected targets (by default the first NUM_TARGET_WORDS
+        //   answers of this length, in file order); remote grading can check hints against them all
+        let corpus = slf.borrow().corpus.clone();
+        let answer_key = match grade_local {
+            true => selection.select(&corpus.answers_of_length(word_length), NUM_TARGET_WORDS),
+            false => corpus.answers_of_length(word_length),
+        };
+        let num_targets = match grade_local {
+            true => answer_key.len(),
+            false => NUM_TARGET_WORDS,
+        };
+        if num_targets == 0 {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Answer key has no {}-letter words to grade against",
+                word_length
+            )));
+        }
 
         // check for non-deterministic guess() behavior
-        Self::check_deterministic_behavior(&slf)?;
+        Self::check_deterministic_behavior(&slf, hard_mode)?;
+        slf.borrow_mut().last_games.clear();
+        let style = GuessStyle::detect(slf.as_any(), "guess")?;
 
-        // Each element of this vector is a guess history per target word that we grow via calling
+        // Each element of histories is a guess history per target word that we grow via calling
         //   user's guess() method and sending guesses to backend to recieve hints.
-        let hint_map: Vec<Bound<PyList>> =
-            (0..NUM_TARGET_WORDS).map(|_| PyList::empty(py)).collect();
+        let mut games = HintGames {
+            histories: vec![GuessHistory::default(); num_targets],
+            style,
+            hard_mode,
+            answers: match grade_local {
+                true => &answer_key,
+                false => &[],
+            },
+            server_candidates: match verify_server_hints {
+                true => vec![answer_key.clone(); num_targets],
+                false => vec![],
+            },
+        };
+
+        // In fibble mode each target gets its own lying grader. Target seeds are drawn from
+        //   one generator so the whole run is reproducible from one seed, while runs with
+        //   nearby seeds (seed, seed + 1, ...) still get unrelated lies
+        let mut liars: Option<Vec<LyingGrader>> = match grading_mode {
+            GradingMode::Fibble => {
+                let mut target_seeds = SplitMix64::new(seed);
+                Some(
+                    answer_key[..num_targets]
+                        .iter()
+                        .map(|answer| {
+                            LyingGrader::new(
+                                answer.to_string(),
+                                lies_per_row,
+                                target_seeds.next_u64(),
+                            )
+                        })
+                        .collect(),
+                )
+            }
+            _ => None,
+        };
 
+        let mode = match (hard_mode, grading_mode) {
+            (true, _) => ", hard mode".to_string(),
+            (false, GradingMode::Fibble) => {
+                format!(", fibble with {} lies per row, seed {}", lies_per_row, seed)
+            }
+            _ => String::new(),
+        };
         match grade_local {
             true => {
-                py_print(py, "Beginning evaluation (local grading)")?;
+                py_print(py, &format!("Beginning evaluation (local grading{})", mode))?;
             }
             false => {
-                py_print(py, "Beginning evaluation (remote grading)")?;
+                py_print(
+                    py,
+                    &format!("Beginning evaluation (remote grading{})", mode),
+                )?;
                 slf.borrow().send_start_signal_to_server(team_id)?;
             }
         }
 
-        for _ in 0..MAX_GUESSES {
-            let mut guesses = vec![];
-            for hint_list in hint_map.iter() {
-                // Skip calling guess() if they've already guessed the word
-                if hint_list.len() > 0 {
-                    let last_hint: Bound<WordleHint> =
-                        hint_list.get_item(hint_list.len() - 1)?.extract()?;
-                    if last_hint.borrow().is_fully_correct() {
-                        guesses.push(DUMMY_GUESS.to_string());
-                        continue;
-                    }
+        let grader = match (grade_local, liars.as_mut()) {
+            (true, Some(liars)) => RoundGrader::PerTarget(liars),
+            (true, None) => RoundGrader::AnswerKey(&answer_key),
+            (false, _) => RoundGrader::Server,
+        };
+        Self::play_rounds(&slf, &mut games, grader, MAX_GUESSES)?;
+        let hint_map = games.histories;
+
+        if let Some(liars) = &liars {
+            let num_rows: usize = liars.iter().map(|liar| liar.lies().len()).sum();
+            let num_lies: usize = liars
+                .iter()
+                .flat_map(|liar| liar.lies())
+                .map(|row| row.len())
+                .sum();
+            py_print(
+                py,
+                &format!(
+                    "Fibble grader told {} lies over {} rows \
+                     (each target's lied-about tiles are in last_game_records)",
+                    num_lies, num_rows
+                ),
+            )?;
+        }
+
+        slf.borrow_mut().last_games = Self::record_games(
+            &hint_map,
+            grade_local,
+            &answer_key,
+            liars.as_deref(),
+            grading_mode,
+            hard_mode,
+        );
+
+        // Calculate final score
+        let avg_num_guesses = Self::calculate_local_score(&hint_map, grade_local, &answer_key)?;
+        match grade_local {
+            true => {
+                py_print(py, &format!("Team {} local eval completed.", team_id))?;
+                py_print(
+                    py,
+                    &format!(
+                        "Average number of guesses (unweighted) = {:.2}",
+                        avg_num_guesses
+                    ),
+                )?;
+            }
+            false => {
+                py_print(
+                    py,
+                    &format!("Ending team {} evaluation (remote grading)...", team_id),
+                )?;
+                let score = slf.borrow().send_end_signal_to_server(team_id)?;
+                py_print(py, &format!("Team {} remote eval completed.", team_id))?;
+                py_print(
+                    py,
+                    &format!(
+                        "Average number of guesses (unweighted) = {:.2}",
+                        avg_num_guesses
+                    ),
+                )?;
+                py_print(py, &format!("Weighted server score = {:.2}", score))?;
+            }
+        }
+
+        Ok(avg_num_guesses)
+    }
+
+    /// Python exposed method to grade user's guess_boards() function on one multi-board
+    /// game (Dordle/Quordle/Octordle style), one board per answer. Returns the total number
+    /// of guesses it took to clear every board
+    #[pyo3(signature = (answers, logging = true))]
+    pub fn evaluate_boards_on_words(
+        slf: Bound<'_, Self>,
+        answers: Vec<String>,
+        logging: bool,
+    ) -> PyResult<i64> {
+        let py = slf.py();
+        let word_length = slf.borrow().word_length;
+        let num_boards = answers.len();
+        let answers: Vec<String> = answers
+            .iter()
+            .map(|answer| slf.borrow().corpus.normalize(answer))
+            .collect();
+
+        Self::validate_num_boards(num_boards)?;
+        if let Some(answer) = answers
+            .iter()
+            .find(|answer| normalize::word_length(answer) != word_length)
+        {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Answer {} is not {} letters long",
+                answer, word_length
+            )));
+        }
+
+        if logging {
+            py_print(
+                py,
+                &format!("Evaluating bot on answers: {}", answers.join(", ")),
+            )?;
+            py_print(py, "----------------------------------------------------")?;
+        }
+
+        let style = GuessStyle::detect(slf.as_any(), "guess_boards")?;
+        let mut game = MultiBoardGame::new(num_boards);
+        for _ in 0..max_multi_board_guesses(num_boards) {
+            let guess = Self::call_guess_boards(&slf, style, &game)?;
+            let open_boards = game.open_boards();
+            let hints: Vec<WordleHint> = open_boards
+                .iter()
+                .map(|board| grade_guess(&guess, &answers[*board]))
+                .collect();
+            if logging {
+                py_print(py, &WordleHint::new_all_correct(guess).spaced_letters())?;
+                for (board, hint) in open_boards.iter().zip(hints.iter()) {
+                    py_print(
+                        py,
+                        &format!("Board {}: {}", board + 1, hint.emoji_squares()),
+                    )?;
                 }
+            }
+            game.record_guess(hints);
+            if let Some(num_guesses) = game.cleared_after() {
+                return Ok(num_guesses as i64);
+            }
+        }
+
+        Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+            "Failed to clear all {} boards in {} guesses",
+            num_boards,
+            max_multi_board_guesses(num_boards)
+        )))
+    }
+
+    /// Multi-board tournament evaluation. Targets are split into consecutive groups of
+    /// num_boards answers, each played as one game through guess_boards(). The score is the
+    /// average total number of guesses needed to clear every board of a game
+    #[pyo3(signature = (num_boards, grade_local))]
+    pub fn evaluate_boards(
+        slf: Bound<'_, Self>,
+        num_boards: usize,
+        grade_local: bool,
+    ) -> PyResult<f64> {
+        let py = slf.py();
+        let team_id = slf.borrow().team_id.clone();
+        let word_length = slf.borrow().word_length;
 
-                let guess: String = slf.call_method1("guess", (hint_list,))?.extract()?;
-                if !is_valid_word(&guess) {
+        Self::validate_num_boards(num_boards)?;
+        let corpus = slf.borrow().corpus.clone();
+        let answer_key = corpus.answers_of_length(word_length);
+        let num_targets = match grade_local {
+            true => NUM_TARGET_WORDS.min(answer_key.len()),
+            false => {
+                // The server grades a fixed set of targets, so every target must be on a board
+                if !NUM_TARGET_WORDS.is_multiple_of(num_boards) {
                     return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
-                        "Guess {} is not a valid word - must be in corpus",
-                        guess
+                        "Remote multi-board evaluation needs num_boards to divide {}",
+                        NUM_TARGET_WORDS
                     )));
                 }
-                guesses.push(guess);
+                NUM_TARGET_WORDS
             }
+        };
+        let num_games = num_targets / num_boards;
+        if num_games == 0 {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Answer key has too few {}-letter words for {} boards",
+                word_length, num_boards
+            )));
+        }
 
-            // Grade new round of guesses
-            let new_hints = match grade_local {
-                true => slf.borrow().grade_guesses_locally(&guesses)?,
-                false => slf.borrow().submit_guesses_to_server(team_id, &guesses)?,
-            };
+        // check for non-deterministic guess_boards() behavior
+        Self::check_deterministic_boards(&slf, num_boards)?;
+        let mut games = BoardGames {
+            games: (0..num_games)
+                .map(|_| MultiBoardGame::new(num_boards))
+                .collect(),
+            num_boards,
+            style: GuessStyle::detect(slf.as_any(), "guess_boards")?,
+        };
 
-            // Update hint_map with the new hints
-            for (i, hint_list) in hint_map.iter().enumerate() {
-                let hint = Py::new(py, new_hints[i].clone())?;
-                hint_list.append(hint)?;
+        match grade_local {
+            true => {
+                py_print(
+                    py,
+                    &format!("Beginning {}-board evaluation (local grading)", num_boards),
+                )?;
+            }
+            false => {
+                py_print(
+                    py,
+                    &format!("Beginning {}-board evaluation (remote grading)", num_boards),
+                )?;
+                slf.borrow().send_start_signal_to_server(&team_id)?;
             }
         }
 
+        let grader = match grade_local {
+            true => RoundGrader::AnswerKey(&answer_key),
+            false => RoundGrader::Server,
+        };
+        Self::play_rounds(
+            &slf,
+            &mut games,
+            grader,
+            max_multi_board_guesses(num_boards),
+        )?;
+
         // Calculate final score
-        let avg_num_guesses: f64;
-        avg_num_guesses = Self::calculate_local_score(&hint_map)?;
+        let mut tot_guesses = 0.0;
+        for (game_idx, game) in games.games.iter().enumerate() {
+            match game.cleared_after() {
+                Some(num_guesses) => tot_guesses += num_guesses as f64,
+                None => {
+                    let start = game_idx * num_boards;
+                    let words = answer_key
+                        .get(start..start + num_boards)
+                        .map_or("(graded remotely)".to_string(), |words| words.join(", "));
+                    return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                        "Failed to clear boards: {}",
+                        words
+                    )));
+                }
+            }
+        }
+        let avg_num_guesses = tot_guesses / num_games as f64;
+
         match grade_local {
             true => {
-                py_print(py, &format!("Team {} local eval completed.", team_id))?;
                 py_print(
                     py,
                     &format!(
-                        "Average number of guesses (unweighted) = {:.2}",
-                        avg_num_guesses
+                        "Team {} local {}-board eval completed.",
+                        team_id, num_boards
                     ),
                 )?;
             }
@@ -138,57 +909,607 @@ impl UChicagoWordleBotBase {
                     py,
                     &format!("Ending team {} evaluation (remote grading)...", team_id),
                 )?;
-                let score = slf.borrow().send_end_signal_to_server(team_id)?;
-                py_print(py, &format!("Team {} remote eval completed.", team_id))?;
+                let score = slf.borrow().send_end_signal_to_server(&team_id)?;
                 py_print(
                     py,
                     &format!(
-                        "Average number of guesses (unweighted) = {:.2}",
-                        avg_num_guesses
+                        "Team {} remote {}-board eval completed.",
+                        team_id, num_boards
                     ),
                 )?;
                 py_print(py, &format!("Weighted server score = {:.2}", score))?;
             }
         }
+        py_print(
+            py,
+            &format!(
+                "Average number of guesses to clear all boards = {:.2}",
+                avg_num_guesses
+            ),
+        )?;
+
+        Ok(avg_num_guesses)
+    }
+
+    /// Python exposed method to grade user's guess_counts() function on a single word with
+    /// count-only (Jotto/Mastermind) feedback
+    #[pyo3(signature = (answer, logging = true))]
+    pub fn evaluate_counts_on_word(
+        slf: Bound<'_, Self>,
+        answer: String,
+        logging: bool,
+    ) -> PyResult<i64> {
+        let py = slf.py();
+        let word_length = slf.borrow().word_length;
+        let answer = slf.borrow().corpus.normalize(&answer);
+
+        if normalize::word_length(&answer) != word_length {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Answer {} is not {} letters long",
+                answer, word_length
+            )));
+        }
+
+        if logging {
+            py_print(
+                py,
+                &format!("Evaluating bot on answer (count feedback): {}", answer),
+            )?;
+            py_print(py, "----------------------------------------------------")?;
+        }
+
+        let mut history = vec![];
+        for num_guesses in 1..=MAX_GUESSES {
+            let guess = Self::call_guess_counts(&slf, &history)?;
+            let hint = grade_counts(&guess, &answer);
+            if logging {
+                hint.visualize_hint(py)?;
+            }
+            if hint.is_fully_correct() {
+                return Ok(num_guesses as i64);
+            }
+            history.push(hint);
+        }
+
+        Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+            "Failed to guess answer in {} guesses",
+            MAX_GUESSES
+        )))
+    }
+
+    /// Tournament evaluation with count-only (Jotto/Mastermind) feedback through
+    /// guess_counts(). Server hints are collapsed to counts before the bot sees them
+    #[pyo3(signature = (grade_local))]
+    pub fn evaluate_counts(slf: Bound<'_, Self>, grade_local: bool) -> PyResult<f64> {
+        let py = slf.py();
+        let team_id = slf.borrow().team_id.clone();
+        let word_length = slf.borrow().word_length;
+
+        let corpus = slf.borrow().corpus.clone();
+        let answer_key = corpus.answers_of_length(word_length);
+        let num_targets = match grade_local {
+            true => NUM_TARGET_WORDS.min(answer_key.len()),
+            false => NUM_TARGET_WORDS,
+        };
+        if num_targets == 0 {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Answer key has no {}-letter words to grade against",
+                word_length
+            )));
+        }
+
+        // check for non-deterministic guess_counts() behavior
+        Self::check_deterministic_counts(&slf)?;
+        let mut games = CountGames {
+            histories: vec![vec![]; num_targets],
+        };
+
+        match grade_local {
+            true => {
+                py_print(py, "Beginning evaluation (local grading, count feedback)")?;
+            }
+            false => {
+                py_print(py, "Beginning evaluation (remote grading, count feedback)")?;
+                slf.borrow().send_start_signal_to_server(&team_id)?;
+            }
+        }
+
+        let grader = match grade_local {
+            true => RoundGrader::AnswerKey(&answer_key),
+            false => RoundGrader::Server,
+        };
+        Self::play_rounds(&slf, &mut games, grader, MAX_GUESSES)?;
+
+        // Calculate final score
+        let mut tot_guesses = 0.0;
+        for (i, history) in games.histories.iter().enumerate() {
+            if !history.last().is_some_and(|hint| hint.is_fully_correct()) {
+                return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                    "Failed to guess word: {}",
+                    answer_key.get(i).copied().unwrap_or("(graded remotely)")
+                )));
+            }
+            tot_guesses += history.len() as f64;
+        }
+        let avg_num_guesses = tot_guesses / num_targets as f64;
+
+        match grade_local {
+            true => {
+                py_print(
+                    py,
+                    &format!("Team {} local count feedback eval completed.", team_id),
+                )?;
+            }
+            false => {
+                py_print(
+                    py,
+                    &format!("Ending team {} evaluation (remote grading)...", team_id),
+                )?;
+                let score = slf.borrow().send_end_signal_to_server(&team_id)?;
+                py_print(
+                    py,
+                    &format!("Team {} remote count feedback eval completed.", team_id),
+                )?;
+                py_print(py, &format!("Weighted server score = {:.2}", score))?;
+            }
+        }
+        py_print(
+            py,
+            &format!(
+                "Average number of guesses (unweighted) = {:.2}",
+                avg_num_guesses
+            ),
+        )?;
 
         Ok(avg_num_guesses)
     }
 
-    pub fn guess(&self, _py: Python, _hints: Vec<Py<WordleHint>>) -> PyResult<String> {
+    pub fn guess(&self, _py: Python, _hints: &Bound<'_, PyAny>) -> PyResult<String> {
         Err(PyNotImplementedError::new_err(
             "Subclass must implement the guess() method",
         ))
     }
+
+    pub fn guess_boards(
+        &self,
+        _py: Python,
+        _board_hints: Vec<Vec<Py<WordleHint>>>,
+    ) -> PyResult<String> {
+        Err(PyNotImplementedError::new_err(
+            "Subclass must implement the guess_boards() method for multi-board evaluation",
+        ))
+    }
+
+    pub fn guess_counts(&self, _py: Python, _hints: Vec<Py<CountHint>>) -> PyResult<String> {
+        Err(PyNotImplementedError::new_err(
+            "Subclass must implement the guess_counts() method for count feedback evaluation",
+        ))
+    }
 }
 
 impl UChicagoWordleBotBase {
+    /// Play one game and keep its record as the bot's last game, whether or not it was solved.
+    /// `answer` is None when the grader doesn't commit to one up front
+    fn play_game(
+        slf: &Bound<'_, Self>,
+        grader: &mut dyn Grader,
+        answer: Option<&str>,
+        grading_mode: GradingMode,
+        hard_mode: bool,
+        renderer: Option<Renderer>,
+    ) -> PyResult<usize> {
+        let mut history = vec![];
+        let result = Self::play_turns(slf, grader, answer, hard_mode, renderer, &mut history);
+        // HTML is drawn as one board once the game is over rather than row by row. Either way
+        //   the game ends with the keyboard showing what was learned about each letter
+        if let Some(renderer) = renderer {
+            let keyboard = renderer.render_keyboard(&KeyboardState::from_hints(&history)?);
+            match renderer.style() {
+                RenderStyle::Html => {
+                    let board = renderer.render_game(&history);
+                    py_display_html(slf.py(), &format!("{}<br>{}", board, keyboard))?;
+                }
+                _ => {
+                    py_print(slf.py(), "Keyboard:")?;
+                    py_print(slf.py(), &keyboard)?;
+                }
+            }
+        }
+
+        let outcome = match result {
+            Ok(_) => GameOutcome::Solved,
+            Err(_) => GameOutcome::Failed,
+        };
+        let answer = answer.map(str::to_string).or_else(|| match outcome {
+            GameOutcome::Solved => history.last().map(|hint| hint.word().to_string()),
+            GameOutcome::Failed => None,
+        });
+        slf.borrow_mut().last_games = vec![GameRecord::new(
+            answer,
+            history,
+            outcome,
+            grading_mode.name().to_string(),
+            hard_mode,
+        )];
+        result
+    }
+
+    /// Call guess() until the answer is guessed (or, without a known answer, the grader gives
+    /// a fully correct hint), collecting every hint
+    fn play_turns(
+        slf: &Bound<'_, Self>,
+        grader: &mut dyn Grader,
+        answer: Option<&str>,
+        hard_mode: bool,
+        renderer: Option<Renderer>,
+        history: &mut Vec<WordleHint>,
+    ) -> PyResult<usize> {
+        let py = slf.py();
+        let style = GuessStyle::detect(slf.as_any(), "guess")?;
+
+        for num_guesses in 1..=MAX_GUESSES {
+            let history_so_far = GuessHistory::new(history.clone());
+            let guess = Self::call_guess(slf, style, &history_so_far, hard_mode)?;
+            if hard_mode {
+                Self::validate_hard_mode(&guess, history)?;
+            }
+            let hint = grader.grade(&guess);
+            if let Some(renderer) = renderer.filter(|r| r.style() != RenderStyle::Html) {
+                py_print(py, &renderer.render_hint(&hint))?;
+            }
+            history.push(hint);
+            let solved = solved_at(&history[history.len() - 1..], answer).is_some();
+            if solved {
+                return Ok(num_guesses);
+            }
+        }
+
+        Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+            "Failed to guess answer in {} guesses",
+            MAX_GUESSES
+        )))
+    }
+
+    /// Play one game against an AdversarialGrader over the answer key
+    fn play_adversarial_game(
+        slf: &Bound<'_, Self>,
+        hard_mode: bool,
+        renderer: Option<Renderer>,
+    ) -> PyResult<(usize, AdversarialGrader)> {
+        let corpus = slf.borrow().corpus.clone();
+        let word_length = slf.borrow().word_length;
+        let answer_key = corpus.answers_of_length(word_length);
+        let mut grader = AdversarialGrader::new(&answer_key).ok_or_else(|| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Answer key has no {}-letter words to grade against",
+                word_length
+            ))
+        })?;
+        let num_guesses = Self::play_game(
+            slf,
+            &mut grader,
+            None,
+            GradingMode::Adversarial,
+            hard_mode,
+            renderer,
+        )?;
+        Ok((num_guesses, grader))
+    }
+
+    fn report_adversarial_game(
+        py: Python,
+        num_guesses: usize,
+        grader: &AdversarialGrader,
+    ) -> PyResult<()> {
+        if let Some(forced_after) = grader.forced_after() {
+            py_print(
+                py,
+                &format!(
+                    "Adversary forced down to one word ({}) after {} guesses",
+                    grader.remaining()[0],
+                    forced_after
+                ),
+            )?;
+        }
+        py_print(py, &format!("Solved in {} guesses", num_guesses))
+    }
+
+    /// Print which tiles of each row were lies, after a fibble game
+    fn report_lies(py: Python, grader: &LyingGrader) -> PyResult<()> {
+        py_print(py, "Lies told by the fibble grader:")?;
+        for (row, lies) in grader.lies().iter().enumerate() {
+            if lies.is_empty() {
+                continue;
+            }
+            let label = match lies.len() {
+                1 => "tile",
+                _ => "tiles",
+            };
+            let tiles: Vec<String> = lies.iter().map(|tile| (tile + 1).to_string()).collect();
+            py_print(
+                py,
+                &format!("  Guess {}: {} {}", row + 1, label, tiles.join(", ")),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Reject grading options that don't fit together
+    fn validate_grading_options(
+        grading_mode: GradingMode,
+        grade_local: bool,
+        hard_mode: bool,
+        lies_per_row: usize,
+        word_length: usize,
+    ) -> PyResult<()> {
+        if grading_mode != GradingMode::Standard && !grade_local {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "{} grading is only available with grade_local=True",
+                grading_mode.name()
+            )));
+        }
+        if grading_mode == GradingMode::Fibble {
+            if hard_mode {
+                return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                    "Hard mode cannot be combined with fibble grading, since hints may be lies",
+                ));
+            }
+            if lies_per_row > word_length {
+                return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                    "Cannot tell {} lies per row with {}-letter words",
+                    lies_per_row, word_length
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Grade a round of guesses with one stateful grader per target
+    fn grade_guesses_with<G: Grader>(graders: &mut [G], guesses: &[String]) -> Vec<WordleHint> {
+        graders
+            .iter_mut()
+            .zip(guesses)
+            .map(|(grader, guess)| match guess == DUMMY_GUESS {
+                true => WordleHint::new_all_correct(guess.clone()),
+                false => grader.grade(guess),
+            })
+            .collect()
+    }
+
+    /// Adversarial tournament evaluation. The adversary ignores the answer key order and
+    /// guess() is deterministic, so every target would play out identically: play it once
+    fn evaluate_adversarial(slf: &Bound<'_, Self>, hard_mode: bool) -> PyResult<f64> {
+        let py = slf.py();
+        let team_id = slf.borrow().team_id.clone();
+
+        Self::check_deterministic_behavior(slf, hard_mode)?;
+
+        let mode = match hard_mode {
+            true => ", hard mode",
+            false => "",
+        };
+        py_print(
+            py,
+            &format!("Beginning evaluation (adversarial local grading{})", mode),
+        )?;
+        let (num_guesses, grader) = Self::play_adversarial_game(slf, hard_mode, None)?;
+        Self::report_adversarial_game(py, num_guesses, &grader)?;
+        py_print(py, &format!("Team {} adversarial eval completed.", team_id))?;
+
+        Ok(num_guesses as f64)
+    }
+
+    /// Play num_rounds rounds of a tournament run: collect a guess per target, grade the
+    /// round and hand the hints back
+    fn play_rounds(
+        slf: &Bound<'_, Self>,
+        games: &mut dyn TournamentGames,
+        mut grader: RoundGrader,
+        num_rounds: usize,
+    ) -> PyResult<()> {
+        let team_id = slf.borrow().team_id.clone();
+        for _ in 0..num_rounds {
+            let guesses = games.next_guesses(slf)?;
+
+            // Grade new round of guesses
+            let new_hints = match &mut grader {
+                RoundGrader::AnswerKey(answer_key) => {
+                    slf.borrow().grade_guesses_locally(&guesses, answer_key)?
+                }
+                RoundGrader::PerTarget(graders) => Self::grade_guesses_with(graders, &guesses),
+                RoundGrader::Server => slf.borrow().submit_guesses_to_server(&team_id, &guesses)?,
+            };
+            games.record_hints(&guesses, new_hints)?;
+        }
+        Ok(())
+    }
+
+    /// Call user's guess_boards() with one hint list (or GameState) per board, closed boards
+    /// included, so their history ends in a fully correct hint, and validate the guess
+    fn call_guess_boards(
+        slf: &Bound<'_, Self>,
+        style: GuessStyle,
+        game: &MultiBoardGame,
+    ) -> PyResult<String> {
+        let guess = Self::call_guess_boards_unchecked(slf, style, game)?;
+        slf.borrow().validate_guess(&guess)
+    }
+
+    /// Call user's guess_boards() without validating the guess
+    fn call_guess_boards_unchecked(
+        slf: &Bound<'_, Self>,
+        style: GuessStyle,
+        game: &MultiBoardGame,
+    ) -> PyResult<String> {
+        let py = slf.py();
+        let board_hints = PyList::empty(py);
+        for (board, history) in game.histories().iter().enumerate() {
+            match style {
+                GuessStyle::Hints => {
+                    let hint_list = PyList::empty(py);
+                    for hint in history {
+                        hint_list.append(Py::new(py, hint.clone())?)?;
+                    }
+                    board_hints.append(hint_list)?;
+                }
+                GuessStyle::State => board_hints.append(GameState::new(
+                    GuessHistory::new(history.clone()),
+                    game.num_guesses() + 1,
+                    max_multi_board_guesses(game.num_boards()),
+                    false,
+                    Some(board),
+                ))?,
+            }
+        }
+
+        slf.call_method1("guess_boards", (board_hints,))?.extract()
+    }
+
+    /// Call user's guess() with a read-only copy of the history, or the full GameState for
+    /// bots that take one, and validate the guess
+    fn call_guess(
+        slf: &Bound<'_, Self>,
+        style: GuessStyle,
+        history: &GuessHistory,
+        hard_mode: bool,
+    ) -> PyResult<String> {
+        let guess: String = match style {
+            GuessStyle::Hints => slf.call_method1("guess", (history.clone(),))?,
+            GuessStyle::State => {
+                let turn = history.hints().len() + 1;
+                let state = GameState::new(history.clone(), turn, MAX_GUESSES, hard_mode, None);
+                slf.call_method1("guess", (state,))?
+            }
+        }
+        .extract()?;
+        slf.borrow().validate_guess(&guess)
+    }
+
+    /// Call user's guess_counts() with the count feedback so far and validate the guess
+    fn call_guess_counts(slf: &Bound<'_, Self>, history: &[CountHint]) -> PyResult<String> {
+        let guess = Self::call_guess_counts_unchecked(slf, history)?;
+        slf.borrow().validate_guess(&guess)
+    }
+
+    /// Call user's guess_counts() without validating the guess
+    fn call_guess_counts_unchecked(
+        slf: &Bound<'_, Self>,
+        history: &[CountHint],
+    ) -> PyResult<String> {
+        let py = slf.py();
+        let hint_list = PyList::empty(py);
+        for hint in history {
+            hint_list.append(Py::new(py, hint.clone())?)?;
+        }
+
+        slf.call_method1("guess_counts", (hint_list,))?.extract()
+    }
+
+    fn validate_num_boards(num_boards: usize) -> PyResult<()> {
+        if !is_valid_num_boards(num_boards) {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Number of boards must be between 1 and {}, got {}",
+                MAX_BOARDS, num_boards
+            )));
+        }
+        Ok(())
+    }
+
+    /// Reject guesses that are the wrong length or not in the corpus
+    /// and return the guess in the normalized form used for grading
+    fn validate_guess(&self, guess: &str) -> PyResult<String> {
+        let guess = self.corpus.normalize(guess);
+        if normalize::word_length(&guess) != self.word_length {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Guess {} is not a valid word - must be {} letters long",
+                guess, self.word_length
+            )));
+        }
+        if !self.corpus.is_valid_word(&guess) {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Guess {} is not a valid word - must be in corpus",
+                guess
+            )));
+        }
+        Ok(guess)
+    }
+
+    /// Reject guesses that break hard-mode rules set by earlier hints
+    fn validate_hard_mode(guess: &str, history: &[WordleHint]) -> PyResult<()> {
+        check_hard_mode(guess, history).map_err(|violation| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(violation.to_string())
+        })
+    }
+
     /// Check for non-deterministic guess() behavior by calling guess() multiple times
     /// with the same hint list and verifying all results are identical
-    fn check_deterministic_behavior(slf: &Bound<'_, Self>) -> PyResult<()> {
-        let py = slf.py();
+    fn check_deterministic_behavior(slf: &Bound<'_, Self>, hard_mode: bool) -> PyResult<()> {
+        let probe_hint = Self::probe_hint(slf);
+        let style = GuessStyle::detect(slf.as_any(), "guess")?;
+        Self::check_same_guesses("guess", || {
+            let history = GuessHistory::new(vec![probe_hint.clone()]);
+            match style {
+                GuessStyle::Hints => slf.call_method1("guess", (history,))?,
+                GuessStyle::State => slf.call_method1(
+                    "guess",
+                    (GameState::new(history, 2, MAX_GUESSES, hard_mode, None),),
+                )?,
+            }
+            .extract()
+        })
+    }
+
+    /// Same check for guess_boards(), with the probe hint on every board
+    fn check_deterministic_boards(slf: &Bound<'_, Self>, num_boards: usize) -> PyResult<()> {
+        let mut game = MultiBoardGame::new(num_boards);
+        game.record_guess(vec![Self::probe_hint(slf); num_boards]);
+        let style = GuessStyle::detect(slf.as_any(), "guess_boards")?;
+        Self::check_same_guesses("guess_boards", || {
+            Self::call_guess_boards_unchecked(slf, style, &game)
+        })
+    }
+
+    /// Same check for guess_counts(), with the probe hint collapsed to counts
+    fn check_deterministic_counts(slf: &Bound<'_, Self>) -> PyResult<()> {
+        let history = vec![CountHint::from_hint(&Self::probe_hint(slf))];
+        Self::check_same_guesses("guess_counts", || {
+            Self::call_guess_counts_unchecked(slf, &history)
+        })
+    }
+
+    /// A one-hint history to probe guess methods with: the first corpus word of the bot's
+    /// length (or "store" for 5 letters) with only its last letter present
+    fn probe_hint(slf: &Bound<'_, Self>) -> WordleHint {
+        let word_length = slf.borrow().word_length;
+        let corpus = slf.borrow().corpus.clone();
+        let probe_word = match word_length {
+            WORD_LENGTH => "store",
+            _ => corpus
+                .guesses_of_length(word_length)
+                .first()
+                .copied()
+                .unwrap_or("store"),
+        };
+        let probe_length = normalize::word_length(probe_word);
+        let mut probe_hints = vec![HintType::Absent; probe_length];
+        probe_hints[probe_length - 1] = HintType::Present;
+        WordleHint::new(probe_word.to_string(), probe_hints)
+    }
+
+    /// Make the same guess call 10 times and reject the bot unless every guess is identical
+    fn check_same_guesses(
+        method: &str,
+        mut attempt: impl FnMut() -> PyResult<String>,
+    ) -> PyResult<()> {
         let mut attempts = vec![];
         for _ in 0..10 {
-            let hint_list = PyList::empty(py);
-            hint_list.append(Py::new(
-                py,
-                WordleHint::new(
-                    "store".to_string(),
-                    [
-                        HintType::Absent,
-                        HintType::Absent,
-                        HintType::Absent,
-                        HintType::Absent,
-                        HintType::Present,
-                    ],
-                ),
-            )?)?;
-            let guess: String = slf.call_method1("guess", (hint_list,))?.extract()?;
-            attempts.push(guess);
+            attempts.push(attempt()?);
         }
         if attempts.iter().any(|g| g != &attempts[0]) {
             return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
-                "We like determinism! But your guess() method is not deterministic. \
-                 Please make it return the same guess for a given unique hint list."
+                "We like determinism! But your {}() method is not deterministic. \
+                 Please make it return the same guess for a given unique hint list.",
+                method
             )));
         }
         Ok(())
@@ -208,18 +1529,51 @@ impl UChicagoWordleBotBase {
         backend_client::submit_guesses(team_id, guesses)
     }
 
-    /// Grade a round of guesses locally and return hints
-    fn grade_guesses_locally(&self, guesses: &[String]) -> Result<Vec<WordleHint>, PyErr> {
-        let answer_key = get_grading_answer_key();
+    fn word_list(&self, use_corpus: bool) -> &[String] {
+        match use_corpus {
+            true => self.corpus.guesses(),
+            false => self.corpus.answers(),
+        }
+    }
+
+    /// Narrow each target's possible answers with the hints the server just sent, and reject
+    /// the round if any target's hints can no longer come from a word in the answer list
+    fn check_server_hints(
+        candidates: &mut [Vec<&str>],
+        guesses: &[String],
+        hints: &[WordleHint],
+    ) -> PyResult<()> {
+        for (target, (guess, hint)) in guesses.iter().zip(hints).enumerate() {
+            if guess == DUMMY_GUESS {
+                continue;
+            }
+            retain_consistent(&mut candidates[target], hint);
+            if candidates[target].is_empty() {
+                return Err(PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+                    "Server hint {} for guess {} on target {} is inconsistent with the \
+                     earlier hints: no word in the answer list fits them all",
+                    hint.hints(),
+                    guess,
+                    target
+                )));
+            }
+        }
+        Ok(())
+    }
 
+    /// Grade a round of guesses locally and return hints
+    fn grade_guesses_locally(
+        &self,
+        guesses: &[String],
+        answer_key: &[&str],
+    ) -> Result<Vec<WordleHint>, PyErr> {
         let mut hints = vec![];
         for (i, guess) in guesses.iter().enumerate() {
-            let hint;
-            if guess == DUMMY_GUESS {
-                hint = WordleHint::new_all_correct(guess.clone());
+            let hint = if guess == DUMMY_GUESS {
+                WordleHint::new_all_correct(guess.clone())
             } else {
-                hint = grade_guess(guess, answer_key[i]);
-            }
+                grade_guess(guess, answer_key[i])
+            };
             hints.push(hint);
         }
         Ok(hints)
@@ -230,34 +1584,69 @@ impl UChicagoWordleBotBase {
         backend_client::send_end_signal(team_id)
     }
 
+    /// Build a GameRecord per target from the hint map, dropping the dummy-guess hints that
+    /// follow a solved target. Remotely graded answers are only known once solved
+    fn record_games(
+        hint_map: &[GuessHistory],
+        grade_local: bool,
+        answer_key: &[&str],
+        liars: Option<&[LyingGrader]>,
+        grading_mode: GradingMode,
+        hard_mode: bool,
+    ) -> Vec<GameRecord> {
+        let mut records = vec![];
+        for (i, history) in hint_map.iter().enumerate() {
+            let mut hints = history.hints().to_vec();
+            let answer = grade_local.then(|| answer_key[i]);
+            let outcome = match solved_at(&hints, answer) {
+                Some(solved_at) => {
+                    hints.truncate(solved_at + 1);
+                    GameOutcome::Solved
+                }
+                None => GameOutcome::Failed,
+            };
+            let answer = match (grade_local, outcome) {
+                (true, _) => Some(answer_key[i].to_string()),
+                (false, GameOutcome::Solved) => hints.last().map(|hint| hint.word().to_string()),
+                (false, GameOutcome::Failed) => None,
+            };
+            let record = GameRecord::new(
+                answer,
+                hints,
+                outcome,
+                grading_mode.name().to_string(),
+                hard_mode,
+            );
+            records.push(match liars {
+                Some(liars) => record.with_lies(liars[i].lies().to_vec()),
+                None => record,
+            });
+        }
+        records
+    }
+
     /// Calculate the average number of guesses it took to guess all the words based on hint map (diff from server metric)
-    fn calculate_local_score(hint_map: &[Bound<PyList>]) -> Result<f64, PyErr> {
+    fn calculate_local_score(
+        hint_map: &[GuessHistory],
+        grade_local: bool,
+        answer_key: &[&str],
+    ) -> Result<f64, PyErr> {
         let mut tot_guesses = 0.0;
 
-        for (i, hint_list) in hint_map.iter().enumerate() {
-            if hint_list.len() > 0 {
-                let last_hint: Bound<WordleHint> =
-                    hint_list.get_item(hint_list.len() - 1)?.extract()?;
-                if !last_hint.borrow().is_fully_correct() {
+        for (i, history) in hint_map.iter().enumerate() {
+            if !history.hints().is_empty() {
+                // Find number of guesses it took for the given word
+                let answer = grade_local.then(|| answer_key[i]);
+                let Some(first_correct_index) = solved_at(history.hints(), answer) else {
                     return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
                         "Failed to guess word: {}",
-                        get_grading_answer_key()[i]
+                        answer.unwrap_or("(graded remotely)")
                     )));
-                }
-                // Find number of guesses it took for the given word
-                let first_correct_index = hint_list
-                    .iter()
-                    .position(|hint| {
-                        hint.extract::<Bound<WordleHint>>()
-                            .ok()
-                            .map(|h| h.borrow().is_fully_correct())
-                            .unwrap_or(false)
-                    })
-                    .unwrap();
+                };
                 tot_guesses += (first_correct_index + 1) as f64;
             }
         }
 
-        Ok(tot_guesses / NUM_TARGET_WORDS as f64)
+        Ok(tot_guesses / hint_map.len() as f64)
     }
 }
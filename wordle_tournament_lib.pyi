@@ -3,7 +3,7 @@ Wordle Tournament Library
 
 Library written in Rust for running a Wordle tournament. Written by Kathir Meyyappan and Akash Piya."""
 
-from typing import List, Tuple
+from typing import Callable, Dict, Iterable, Iterator, List, Optional, Tuple, Union
 
 class WordleHint:
     """
@@ -14,6 +14,9 @@ class WordleHint:
         - 'O': Correct letter in correct position (🟩 green)
         - '~': Letter is present but in wrong position (🟨 yellow)  
         - 'X': Letter is absent from the word (⬜ gray)
+
+    Hints can also be given as emoji squares (🟩🟨⬜, or ⬛ for gray), G/Y/B letters or
+    2/1/0 digits, and written back out in any of these with format_hints().
     
     Example:
         >>> hint = WordleHint("hello", "O~XX~")
@@ -23,20 +26,48 @@ class WordleHint:
     """
     
     word: str
-    """The 5-letter word that was guessed."""
+    """The word that was guessed (5 letters in the standard tournament)."""
     
     def __init__(self, word: str, hints: str) -> None:
         """
         Create a new WordleHint.
         
         Args:
-            word: A 5-letter word
-            hints: A 5-character string of hint characters ('O', '~', or 'X')
+            word: The guessed word (2 to 12 letters; 5 in the standard tournament)
+            hints: One hint per letter, as 'O~X' symbols, emoji squares, 'GYB' letters or
+                   '210' digits. Whitespace between hints is ignored.
             
         Raises:
-            ValueError: If word is not 5 letters long
+            ValueError: If word is not between 2 and 12 letters long
             ValueError: If hints length doesn't match word length
-            ValueError: If any hint character is invalid
+            ValueError: If any hint character is invalid. The message names the character
+                        and its (1-based) position.
+        """
+        ...
+
+    @staticmethod
+    def from_pattern_code(word: str, code: int) -> "WordleHint":
+        """
+        Create a 5-letter hint from its packed pattern code (0 to 242; position i counts
+        digit * 3**i, with absent = 0, present = 1, correct = 2).
+
+        Raises:
+            ValueError: If word is not 5 letters or code is out of range
+        """
+        ...
+
+    @property
+    def pattern_code(self) -> Optional[int]:
+        """The packed pattern code of a 5-letter hint, None for other lengths."""
+        ...
+
+    def format_hints(self, format: str = "symbols") -> str:
+        """
+        The hint pattern in another encoding: 'symbols' ('O~XX~'), 'emoji' ('🟩🟨⬜⬜🟨'),
+        'letters' ('GYBBY') or 'digits' ('21001'). Every format parses back to the same hint.
+
+        Raises:
+            ValueError: If format is unknown
         """
         ...
     
@@ -60,24 +91,696 @@ class WordleHint:
         """
         ...
     
-    def visualize_hint(self) -> None:
+    def visualize_hint(self, style: str = "emoji", high_contrast: bool = False) -> None:
         """
-        Print a visual representation of the hint using emoji squares.
+        Print a visual representation of the hint.
         
-        Prints two lines:
+        With the default 'emoji' style this prints two lines:
         - Line 1: Letters in uppercase separated by spaces
         - Line 2: Colored squares (🟩/🟨/⬜) matching the hint pattern
         
         Example output:
             H E L L O
             🟩 🟨 ⬜ ⬜ 🟨
+
+        Args:
+            style: 'emoji', 'ansi' (letters on colored tiles, for terminals where emoji
+                   misalign) or 'html' (shown as a grid in Jupyter)
+            high_contrast: Use the colorblind-friendly palette (orange correct, blue present)
         """
         ...
+
+    def render(self, style: str = "emoji", high_contrast: bool = False) -> str:
+        """The hint drawn as in visualize_hint(), returned as a string."""
+        ...
+
+    def _repr_html_(self) -> str:
+        """Jupyter shows hints as a row of Wordle tiles."""
+        ...
     
     def __repr__(self) -> str:
         """Return string representation of WordleHint."""
         ...
 
+    def __str__(self) -> str:
+        """Word and hint pattern, e.g. 'crane O~XXX'."""
+        ...
+
+    def __eq__(self, other: object) -> bool:
+        """Hints are equal when their word and hint pattern are equal."""
+        ...
+
+    def __lt__(self, other: "WordleHint") -> bool:
+        """Hints order by word, then by hint pattern. <=, > and >= work the same way."""
+        ...
+
+    def __le__(self, other: "WordleHint") -> bool: ...
+    def __gt__(self, other: "WordleHint") -> bool: ...
+    def __ge__(self, other: "WordleHint") -> bool: ...
+
+    def __hash__(self) -> int:
+        """Hash of (word, hints), the same in every process, so hints work as dict keys."""
+        ...
+
+    def __len__(self) -> int:
+        """Number of letters."""
+        ...
+
+    def __iter__(self) -> Iterator[Tuple[str, str]]:
+        """Iterate over (letter, hint) pairs, like word_hint_pairs."""
+        ...
+
+    def __reduce__(self) -> Tuple[Callable[[str, str], "WordleHint"], Tuple[str, str]]:
+        """
+        Pickle support, so hints can be cached or sent to multiprocessing workers. Every
+        hint round-trips, including the harness's all-correct hints for solved targets.
+        """
+        ...
+
+    def __copy__(self) -> "WordleHint": ...
+    def __deepcopy__(self, memo: dict) -> "WordleHint": ...
+
+
+class CountHint:
+    """
+    Count-only feedback for a guess, as in Jotto or Mastermind's black/white pegs: how many
+    letters are in the correct position and how many are present elsewhere, without saying
+    which. Duplicate letters are counted with the same rules as WordleHint.
+
+    Example:
+        >>> hint = CountHint("allee", 2, 1)   # graded against "apple"
+        >>> hint.absent
+        2
+    """
+
+    word: str
+    """The word that was guessed."""
+
+    correct: int
+    """Letters in the correct position (green / black pegs)."""
+
+    present: int
+    """Letters in the answer but in the wrong position (yellow / white pegs)."""
+
+    absent: int
+    """Letters not in the answer."""
+
+    def __init__(self, word: str, correct: int, present: int) -> None:
+        """
+        Raises:
+            ValueError: If correct + present is more than the number of letters in word
+        """
+        ...
+
+    def is_fully_correct(self) -> bool:
+        """Whether every letter is in the correct position."""
+        ...
+
+    def visualize_hint(self) -> None:
+        """Print the guess and its counts, e.g. 'C R A N E' / '🟩 x1 🟨 x2'."""
+        ...
+
+    def __repr__(self) -> str: ...
+
+
+class HistoryCheck:
+    """
+    Result of UChicagoWordleBotBase.check_hint_history. Truthy when the history is
+    consistent.
+    """
+
+    consistent: bool
+    """Whether at least one word produces exactly the checked hints."""
+
+    witness: Optional[str]
+    """A word that fits every hint, or None if the history is inconsistent."""
+
+    num_consistent: int
+    """Number of words that fit every hint."""
+
+    first_inconsistent_hint: Optional[int]
+    """Index of the first hint after which no word fits, or None if consistent."""
+
+    def __bool__(self) -> bool: ...
+    def __repr__(self) -> str: ...
+
+
+class Constraints:
+    """
+    Everything a hint list reveals about the answer: fixed greens, positions each letter is
+    known not to occupy, and the minimum and maximum count of each letter. A gray for a
+    letter that is also green or yellow in the same guess caps that letter's count, with the
+    same duplicate-letter rules used for grading.
+
+    Example:
+        >>> c = Constraints([WordleHint("allee", "O~XXO")])   # graded against "apple"
+        >>> c.min_counts["l"], c.max_counts["l"]
+        (1, 1)
+        >>> c.allows("apple")
+        True
+    """
+
+    word_length: Optional[int]
+    """Word length the constraints apply to, None before any hint is added."""
+
+    greens: List[Optional[str]]
+    """Known letter at each position, None where it is still open."""
+
+    excluded_positions: Dict[str, List[int]]
+    """0-based positions each letter is known not to occupy."""
+
+    min_counts: Dict[str, int]
+    """Minimum number of times each revealed letter appears in the answer."""
+
+    max_counts: Dict[str, int]
+    """Maximum count of each letter capped by a gray (0 for letters known to be absent)."""
+
+    def __init__(self, hints: List[WordleHint] = []) -> None:
+        """
+        Raises:
+            ValueError: If the hints have different lengths
+        """
+        ...
+
+    def add_hint(self, hint: WordleHint) -> None:
+        """
+        Fold one more hint into the constraints.
+
+        Raises:
+            ValueError: If the hint length differs from earlier hints
+        """
+        ...
+
+    def merge(self, other: "Constraints") -> None:
+        """
+        Fold another set of constraints into this one.
+
+        Raises:
+            ValueError: If the constraints are for different word lengths
+        """
+        ...
+
+    def allows(self, word: str) -> bool:
+        """Whether the word satisfies every constraint, i.e. could still be the answer."""
+        ...
+
+    def __repr__(self) -> str: ...
+
+
+class KeyboardState:
+    """
+    Per-letter summary of a hint list, like the NYT on-screen keyboard: each letter takes the
+    best hint it has received anywhere (correct over present over absent). Letters are
+    matched exactly as guessed, like Constraints.
+
+    Example:
+        >>> k = KeyboardState([WordleHint("crane", "O~~XX")])
+        >>> k.status("r"), k.status("z")
+        ('~', None)
+        >>> print(k.render())
+    """
+
+    def __init__(self, hints: List[WordleHint] = []) -> None:
+        """
+        Raises:
+            ValueError: If the hints have different lengths
+        """
+        ...
+
+    statuses: Dict[str, str]
+    """Best hint each guessed letter has received, as a symbol ('O', '~' or 'X')."""
+
+    def status(self, letter: str) -> Optional[str]:
+        """Best hint the letter has received as a symbol, or None if it hasn't been guessed."""
+        ...
+
+    def known_positions(self, letter: str) -> List[int]:
+        """0-based positions where the letter is known to be."""
+        ...
+
+    def excluded_positions(self, letter: str) -> List[int]:
+        """0-based positions the letter is known not to occupy."""
+        ...
+
+    def count_bounds(self, letter: str) -> Tuple[int, Optional[int]]:
+        """(minimum, maximum) count of the letter in the answer; the maximum is None until capped."""
+        ...
+
+    def render(self, style: str = "emoji", high_contrast: bool = False) -> str:
+        """
+        The keyboard drawn in 'emoji', 'ansi' or 'html' style. Keys not yet guessed are shown
+        as 🔲 (light gray in ANSI and HTML), and letters outside QWERTY get an extra row.
+        """
+        ...
+
+    def _repr_html_(self) -> str: ...
+
+
+class GameRecord:
+    """
+    A finished single-board game: the answer, every guess with its hint, and the outcome.
+    Saved as JSON like
+    {"answer": "cigar", "hints": [{"word": "crane", "hints": "O~~XX"}, ...],
+     "outcome": "solved", "grading_mode": "standard", "hard_mode": false}
+    """
+
+    answer: Optional[str]
+    """The answer, or None if it was never revealed (unsolved adversarial or remote game)."""
+
+    hints: List[WordleHint]
+    """Every guess with the hint it got, including the solving guess."""
+
+    guesses: List[str]
+    """Guessed words, in order."""
+
+    outcome: str
+    """'solved' or 'failed'."""
+
+    solved: bool
+    num_guesses: int
+
+    grading_mode: str
+    """Grading mode the game was played in: 'standard', 'adversarial' or 'fibble'."""
+
+    hard_mode: bool
+
+    lies: Optional[List[List[int]]]
+    """
+    Fibble games only: the lied-about tile positions (0-based) of each row, in guess order.
+    None for other grading modes. Saved as a "lies" key only when present.
+    """
+
+    def __init__(
+        self,
+        answer: Optional[str],
+        hints: List[WordleHint],
+        solved: bool,
+        grading_mode: str = "standard",
+        hard_mode: bool = False,
+        lies: Optional[List[List[int]]] = None,
+    ) -> None: ...
+
+    def to_json(self) -> str:
+        """This record as a single-line JSON object."""
+        ...
+
+    def render(self, style: str = "emoji", high_contrast: bool = False) -> str:
+        """The whole game drawn in 'emoji', 'ansi' or 'html' style, one row per guess."""
+        ...
+
+    def share_text(self, high_contrast: bool = False) -> str:
+        """
+        The familiar share text: a 'Wordle 3/20' header (X if unsolved, * in hard mode)
+        followed by the emoji grid, with no letters.
+
+        Example:
+            >>> print(bot.last_game_records[0].share_text())
+            Wordle 3/20
+
+            ⬜🟨⬜⬜⬜
+            🟩🟨⬜⬜⬜
+            🟩🟩🟩🟩🟩
+        """
+        ...
+
+    def _repr_html_(self) -> str:
+        """Jupyter shows records as a Wordle board."""
+        ...
+
+    def __eq__(self, other: object) -> bool: ...
+
+    def __hash__(self) -> int:
+        """Equal records hash equally, in every process."""
+        ...
+
+    @staticmethod
+    def from_json(json: str) -> "GameRecord":
+        """
+        Raises:
+            ValueError: If the JSON is not a valid game record
+        """
+        ...
+
+    def __eq__(self, other: object) -> bool: ...
+    def __repr__(self) -> str: ...
+
+
+class GuessHistory:
+    """
+    The hints a bot has received so far in one game, oldest first, as passed to guess().
+    Reads like a list (len, indexing with negative indices and slices, iteration) but is
+    immutable, so a bot cannot change the harness's copy. Accepted anywhere a list of hints
+    is, e.g. by remaining_words() and Constraints(). Use to_list() for a mutable copy.
+
+    Example:
+        >>> def guess(self, hints: GuessHistory) -> str:
+        ...     if not hints:
+        ...         return "crane"
+        ...     return self.remaining_words(hints)[0]
+    """
+
+    def __init__(self, hints: List[WordleHint] = []) -> None: ...
+    def __len__(self) -> int: ...
+    def __getitem__(self, index: Union[int, slice]) -> Union[WordleHint, "GuessHistory"]: ...
+    def __iter__(self) -> Iterator[WordleHint]: ...
+    def __eq__(self, other: object) -> bool:
+        """Equal to another GuessHistory, or to a list of the same hints."""
+        ...
+
+    def __hash__(self) -> int:
+        """Stable across runs and processes, so it can key an on-disk cache."""
+        ...
+
+    turn: int
+    """1-based number of the guess about to be made."""
+
+    def last(self) -> Optional[WordleHint]:
+        """Most recent hint, or None before the first guess."""
+        ...
+
+    def guessed_words(self) -> List[str]:
+        """Guessed words, in order."""
+        ...
+
+    def constraints(self) -> Constraints:
+        """
+        Everything the hints so far reveal about the answer.
+
+        Raises:
+            ValueError: If the hints have different lengths
+        """
+        ...
+
+    def to_list(self) -> List[WordleHint]:
+        """The hints as a new (mutable) list."""
+        ...
+
+
+class GameState:
+    """
+    What guess() receives instead of a GuessHistory for bots that opt in: either set the class
+    attribute `guess_style = "state"`, or give guess() a first parameter annotated GameState
+    (or named `state`). guess_boards() then receives one GameState per board. Bots taking a
+    hint list keep working unchanged.
+
+    Example:
+        >>> class MyBot(UChicagoWordleBotBase):
+        ...     def guess(self, state: GameState) -> str:
+        ...         if state.guesses_remaining == 1:
+        ...             return self.remaining_words(state.history)[0]
+        ...         ...
+    """
+
+    turn: int
+    """1-based number of the guess about to be made."""
+
+    guesses_remaining: int
+    """Guesses left under max_guesses, including the one about to be made."""
+
+    max_guesses: int
+    """Guess limit for the game (MAX_GUESSES, plus one per extra board in multi-board play)."""
+
+    mode: str
+    """'normal' or 'hard'."""
+
+    hard_mode: bool
+
+    board: Optional[int]
+    """0-based board index in multi-board play, None in single-board games."""
+
+    history: GuessHistory
+    """Hints received so far on this board."""
+
+
+def save_game_records(records: List[GameRecord], path: str) -> None:
+    """
+    Save game records: JSON Lines (one record per line) if path ends in '.jsonl', otherwise
+    a JSON array.
+
+    Example:
+        >>> bot.evaluate(grade_local=True)
+        >>> save_game_records(bot.last_game_records, "eval.jsonl")
+    """
+    ...
+
+
+def load_game_records(path: str) -> List[GameRecord]:
+    """
+    Load game records saved by save_game_records(), choosing the format by extension.
+
+    Raises:
+        OSError: If the file cannot be read or does not hold valid game records
+    """
+    ...
+
+
+def parse_share_grid(text: str) -> List[str]:
+    """
+    Read the emoji rows of pasted share text back into a pattern-only history, one
+    'O~X' string per row. Lines that aren't rows of squares (like the header) are skipped,
+    and dark-mode and high-contrast squares are accepted.
+
+    Raises:
+        ValueError: If there are no rows, rows have different lengths, or an all-green row
+            comes before the last row
+    """
+    ...
+
+
+class Corpus:
+    """
+    A guess list and answer list to play with: the embedded English lists, lists loaded from
+    files, or any in-memory iterables of words. Words are NFC-normalized (and optionally
+    accent-stripped), duplicates and blank entries are dropped, and every answer is also a
+    valid guess. Corpus objects are immutable and cheap to share between bots.
+
+    Example:
+        >>> corpus = Corpus(["crane", "cigar", "robot"], ["cigar", "robot"])
+        >>> bot = MyBot("my-team", corpus=corpus)
+    """
+
+    def __init__(
+        self,
+        guesses: Iterable[str],
+        answers: Iterable[str],
+        strip_accents: bool = False,
+        keep_accented: str = "",
+    ) -> None: ...
+
+    @staticmethod
+    def embedded() -> "Corpus":
+        """The word lists compiled into the library."""
+        ...
+
+    @staticmethod
+    def from_files(
+        guesses_path: str,
+        answers_path: str,
+        strip_accents: bool = False,
+        keep_accented: str = "",
+    ) -> "Corpus":
+        """
+        Load word lists from UTF-8 files with one word per line.
+
+        Raises:
+            OSError: If either file cannot be read
+        """
+        ...
+
+    guesses: List[str]
+    """Every valid guess list word, in file order."""
+
+    answers: List[str]
+    """Every answer list word, in file order."""
+
+    def guesses_of_length(self, length: int) -> List[str]: ...
+    def answers_of_length(self, length: int) -> List[str]: ...
+
+    def is_valid_word(self, word: str) -> bool:
+        """Whether the word is accepted as a guess, after normalizing it. Also `word in corpus`."""
+        ...
+
+    def normalize(self, word: str) -> str:
+        """The word in the normalized form the lists are stored in."""
+        ...
+
+    guesses_hash: int
+    """Content hash of the guess list (see word_list_hashes)."""
+
+    answers_hash: int
+    """Content hash of the answer list (see word_list_hashes)."""
+
+    def letter_index(self, use_guesses: bool = False) -> "LetterIndex":
+        """
+        Letter-position index over the answer list (or the guess list, with use_guesses),
+        built on first use. Query results are indices into that list.
+        """
+        ...
+
+    def __contains__(self, word: str) -> bool: ...
+
+
+class LetterIndex:
+    """
+    Precomputed bitsets over a word list: the words with each letter at each position, and
+    the words containing each letter at least n times. Queries are answered with a few
+    bitwise ANDs and return indices into the list, in list order.
+
+    Example:
+        >>> index = Corpus.embedded().letter_index(use_guesses=True)
+        >>> # Third letter A, at least two E's, no S
+        >>> index.query(word_length=5, greens={2: "a"}, min_counts={"e": 2}, max_counts={"s": 0})
+    """
+
+    def __init__(self, words: List[str]) -> None: ...
+
+    def query(
+        self,
+        word_length: Optional[int] = None,
+        greens: Dict[int, str] = {},
+        excluded: Dict[str, List[int]] = {},
+        min_counts: Dict[str, int] = {},
+        max_counts: Dict[str, int] = {},
+    ) -> List[int]:
+        """
+        Indices of the words matching every condition.
+
+        Args:
+            greens: Letter required at each 0-based position
+            excluded: 0-based positions each letter can't occupy
+            min_counts: Minimum number of times each letter appears
+            max_counts: Maximum number of times each letter appears (0 for absent letters)
+        """
+        ...
+
+    def candidates(self, hints: List[WordleHint], word_length: int) -> List[int]:
+        """
+        Indices of the words that could still be the answer after these hints.
+
+        Raises:
+            ValueError: If the hints have different lengths
+        """
+        ...
+
+    def hard_mode_guesses(self, hints: List[WordleHint], word_length: int) -> List[int]:
+        """Indices of the words that are legal hard-mode guesses after these hints."""
+        ...
+
+    def __len__(self) -> int: ...
+
+
+class FrequencyTable:
+    """
+    Per-word frequency counts, for weighting candidate answers by how common they are.
+    The library does not ship frequency data yet (an embedded table is pending a suitably
+    licensed list): load your own with from_text(), from_file() or a dict.
+
+    Example:
+        >>> table = FrequencyTable.from_file("frequencies.txt")
+        >>> weights = table.corpus_priors(smoothing="log")
+    """
+
+    def __init__(self, counts: Dict[str, float]) -> None:
+        """
+        Raises:
+            ValueError: If a count is negative or not finite
+        """
+        ...
+
+    @staticmethod
+    def from_text(text: str) -> "FrequencyTable":
+        """
+        Parse one 'word count' pair per line, separated by whitespace or a comma. Blank lines
+        and lines starting with '#' are skipped; repeated words have their counts added.
+
+        Raises:
+            ValueError: If a line is not a word and a non-negative count
+        """
+        ...
+
+    @staticmethod
+    def from_file(path: str) -> "FrequencyTable":
+        """
+        Read a frequency list file in the from_text() format.
+
+        Raises:
+            OSError: If the file cannot be read
+            ValueError: If a line is malformed
+        """
+        ...
+
+    def count(self, word: str) -> Optional[float]:
+        """Count for a word, or None if the table doesn't have it."""
+        ...
+
+    def priors(
+        self,
+        words: List[str],
+        smoothing: str = "raw",
+        threshold: Optional[float] = None,
+        width: float = 0.5,
+    ) -> List[float]:
+        """
+        Prior weights for the words, in order, summing to 1. Words missing from the table
+        count as 0; if every weight is 0 the prior is uniform.
+
+        Args:
+            smoothing: 'raw' (proportional to counts), 'log' (ln(1 + count)) or 'sigmoid'
+                (a logistic step in log10(count) around threshold)
+            threshold: Count that gets half weight under 'sigmoid'. Defaults to the median
+                nonzero count of the words
+            width: Width of the sigmoid step, in powers of ten
+
+        Raises:
+            ValueError: For an unknown smoothing, or a threshold or width that is not a
+                positive number
+        """
+        ...
+
+    def corpus_priors(
+        self,
+        corpus: Optional[Corpus] = None,
+        use_guesses: bool = False,
+        smoothing: str = "raw",
+        threshold: Optional[float] = None,
+        width: float = 0.5,
+    ) -> List[float]:
+        """
+        priors() for a corpus's answer list (or guess list, with use_guesses), the embedded
+        corpus by default. Table words are normalized the way the corpus normalizes its lists.
+        """
+        ...
+
+    def __len__(self) -> int: ...
+    def __contains__(self, word: str) -> bool: ...
+
+
+def guess_corpus() -> List[str]:
+    """
+    Every valid guess of the embedded corpus, in file order: exactly the words the evaluator
+    accepts by default. Use this instead of shipping a copy of corpus.txt.
+    """
+    ...
+
+
+def answer_list() -> List[str]:
+    """Every word of the embedded answer list, in file order."""
+    ...
+
+
+def is_valid_word(word: str) -> bool:
+    """Whether the evaluator accepts the word as a guess with the embedded corpus."""
+    ...
+
+
+def word_list_hashes() -> Tuple[int, int]:
+    """
+    (guess corpus hash, answer list hash) of the embedded word lists: 64-bit FNV-1a of the
+    words joined by newlines in UTF-8, i.e. of a one-word-per-line file without a trailing
+    newline. Compare against a local copy to detect drift.
+    """
+    ...
+
 
 class UChicagoWordleBotBase:
     """
@@ -91,7 +794,7 @@ class UChicagoWordleBotBase:
         ...     def __init__(self, team_id: str):
         ...         pass
         ...
-        ...     def guess(self, hints: List[WordleHint]) -> str:
+        ...     def guess(self, hints: GuessHistory) -> str:
         ...         # Your guessing logic here
         ...         return "crane"
         >>>
@@ -101,16 +804,74 @@ class UChicagoWordleBotBase:
     team_id: str
     """Unique identifier for the team."""
 
-    def __init__(self, team_id: str) -> None:
+    word_length: int
+    """
+    Word length of the tournament (5 by default). Answers and guesses must have this many
+    letters, and local grading only uses answer key words of this length.
+    """
+
+    def __init__(
+        self, team_id: str, word_length: int = 5, corpus: Optional[Corpus] = None
+    ) -> None:
         """
         Initialize a new Wordle bot.
 
         Args:
             team_id: Unique identifier for your team
+            word_length: Word length of the tournament (2 to 12, default 5)
+            corpus: Word lists to validate and grade with (the embedded lists by default)
+
+        Raises:
+            ValueError: If word_length is out of range
         """
         ...
 
-    def evaluate(self, grade_local: bool) -> float:
+    corpus: Corpus
+    """Word lists guesses are validated and graded against. Assign a Corpus to switch."""
+
+    def use_word_lists(
+        self,
+        guesses_path: str,
+        answers_path: str,
+        strip_accents: bool = False,
+        keep_accented: str = "",
+    ) -> None:
+        """
+        Play with word lists loaded from UTF-8 files (one word per line) instead of the
+        embedded English lists, e.g. to run the tournament in another language. Shorthand
+        for `bot.corpus = Corpus.from_files(...)`.
+
+        Letters are Unicode characters, so 'ñ' or 'ä' each count as one letter and get one
+        hint. Words, answers and guesses are NFC-normalized, so decomposed input such as
+        'n' + combining tilde matches 'ñ'.
+
+        Args:
+            guesses_path: File of valid guesses. Answers are always valid guesses too.
+            answers_path: File of possible answers used for local grading
+            strip_accents: If True, remove diacritics from words and guesses ('á' -> 'a')
+            keep_accented: Letters to keep as-is when stripping accents, e.g. 'ñ'
+
+        Raises:
+            OSError: If either file cannot be read
+        """
+        ...
+
+    def use_embedded_word_lists(self) -> None:
+        """Go back to the embedded English word lists."""
+        ...
+
+    def evaluate(
+        self,
+        grade_local: bool,
+        hard_mode: bool = False,
+        grading_mode: str = "standard",
+        lies_per_row: int = 1,
+        seed: int = 0,
+        sample_seed: Optional[int] = None,
+        stratify: str = "none",
+        split: str = "all",
+        verify_server_hints: bool = False,
+    ) -> float:
         """
         Run the tournament evaluation for this bot.
 
@@ -120,18 +881,58 @@ class UChicagoWordleBotBase:
         Args:
             grade_local: If True, grade guesses locally without contacting the server.
                         If False, submit guesses to tournament server for grading.
+            hard_mode: If True, every guess must reuse each revealed green in place and
+                       include each revealed yellow letter (NYT hard mode).
+            grading_mode: 'standard' grades each target against its answer. 'adversarial'
+                          (local only) picks no answer up front and answers each guess with
+                          the hint that keeps the most answers possible, as Absurdle does.
+                          Since guess() is deterministic, the adversarial game is played once
+                          and the report includes how many guesses it took to force the
+                          adversary down to one word. 'fibble' (local only) grades against
+                          the answer but lies about exactly lies_per_row tiles of every row
+                          that isn't fully correct; a lie never makes a wrong guess look
+                          solved, and a target only counts as solved once its answer is
+                          guessed. A summary of the lies is printed after the evaluation
+                          and each target's lied-about tiles are kept in
+                          last_game_records[i].lies.
+            lies_per_row: Number of wrong tiles per row in fibble mode.
+            seed: Seed for the fibble lies. The same seed gives the same lies every run.
+            sample_seed: (local only) Play a seeded random sample of the answer list instead
+                         of its first 1000 words, which are alphabetically skewed. See
+                         sample_answers().
+            stratify: (local only, needs sample_seed) 'first_letter' or 'difficulty' keeps
+                      each first letter's or difficulty bucket's share of the list.
+            split: (local only) 'train' or 'holdout' plays only that split of the answers.
+            verify_server_hints: (remote only) After every round, check that some word in the
+                                 bot's answer list still fits each target's server hints,
+                                 and stop with RuntimeError if none does. Only useful when the
+                                 server grades against the same answer list as the bot.
 
         Returns:
             Average number of guesses per word (different from server score, which
             will be printed granted that grade_local is False)
 
+        Raises:
+            ValueError: If a guess is invalid or, in hard mode, breaks a hard-mode rule
+            RuntimeError: With verify_server_hints, if no answer fits a target's server hints
+
         Example:
             >>> bot = MyBot("team-123")
             >>> score = bot.evaluate(grade_local=True)   # Test locally
         """
         ...
 
-    def evaluate_on_word(self, answer: str, logging: bool = True) -> int:
+    def evaluate_on_word(
+        self,
+        answer: str,
+        logging: bool = True,
+        hard_mode: bool = False,
+        grading_mode: str = "standard",
+        lies_per_row: int = 1,
+        seed: int = 0,
+        style: str = "emoji",
+        high_contrast: bool = False,
+    ) -> int:
         """
         Evaluate the bot on a single word, returning the number of guesses needed.
 
@@ -142,12 +943,30 @@ class UChicagoWordleBotBase:
             answer: The target word to solve (must be a valid 5-letter word)
             logging: If True (default), prints evaluation progress and visualizes hints.
                     If False, runs silently and only returns the result.
+            hard_mode: If True, every guess must reuse each revealed green in place and
+                       include each revealed yellow letter (NYT hard mode).
+            grading_mode: 'standard', 'adversarial' or 'fibble'. In adversarial mode no
+                          answer is fixed, so `answer` only needs to have the right length.
+                          In fibble mode exactly lies_per_row tiles of every row are lies
+                          (a fully correct row is always truthful, and a lie never makes a
+                          wrong guess look solved). With logging the lied-about tiles of
+                          each row are printed after the game; they are also kept in
+                          last_game_records[0].lies.
+            lies_per_row: Number of wrong tiles per row in fibble mode.
+            seed: Seed for the fibble lies. The same seed gives the same lies every run.
+            style: How logged hints are drawn: 'emoji', 'ansi' (colored terminal tiles) or
+                   'html' (the whole game as one board in Jupyter, shown when it ends)
+            high_contrast: Use the colorblind-friendly palette (orange correct, blue present)
 
         Returns:
             Number of guesses it took to solve the word
 
         Raises:
             ValueError: If the answer is not a valid word
+            ValueError: If hard_mode is True and a guess breaks a hard-mode rule. The message
+                        names the rule and the earlier guess that revealed it.
+            ValueError: If hard_mode is combined with fibble grading, or lies_per_row is
+                        longer than the word
             ValueError: If the bot fails to solve the word in MAX_GUESSES attempts
 
         Example:
@@ -177,15 +996,231 @@ class UChicagoWordleBotBase:
         """
         ...
 
-    def guess(self, hints: List[WordleHint]) -> str:
+    def evaluate_boards(self, num_boards: int, grade_local: bool) -> float:
+        """
+        Run a multi-board (Dordle/Quordle/Octordle style) tournament evaluation.
+
+        Targets are split into consecutive groups of `num_boards` answers. Each group is one
+        game: every guess from `guess_boards()` is graded against each open board, and a
+        board closes once its answer is found. A game may take up to
+        MAX_GUESSES + num_boards - 1 guesses.
+
+        Args:
+            num_boards: Boards per game (1 to 32)
+            grade_local: If True, grade guesses locally. If False, submit them to the
+                         tournament server (num_boards must then divide 1000).
+
+        Returns:
+            Average total number of guesses needed to clear every board of a game
+        """
+        ...
+
+    def evaluate_boards_on_words(self, answers: List[str], logging: bool = True) -> int:
+        """
+        Evaluate `guess_boards()` on a single multi-board game, one board per answer.
+
+        Returns:
+            Total number of guesses it took to clear every board
+
+        Raises:
+            ValueError: If the bot fails to clear every board within the guess limit
+        """
+        ...
+
+    def guess_boards(self, board_hints: List[List[WordleHint]]) -> str:
+        """
+        Make a guess in a multi-board game. Override this to use `evaluate_boards()`.
+
+        Args:
+            board_hints: One hint list per board. A solved board's list ends with a fully
+                         correct hint and gets no further hints.
+
+        Returns:
+            A word guess, graded against every open board
+
+        Raises:
+            NotImplementedError: If not overridden in subclass
+        """
+        ...
+
+    def evaluate_counts(self, grade_local: bool) -> float:
+        """
+        Run tournament evaluation with count-only (Jotto/Mastermind) feedback, calling
+        `guess_counts()` instead of `guess()`.
+
+        Args:
+            grade_local: If True, grade guesses locally. If False, submit them to the
+                         tournament server and collapse its hints into counts.
+
+        Returns:
+            Average number of guesses per word
+        """
+        ...
+
+    def evaluate_counts_on_word(self, answer: str, logging: bool = True) -> int:
+        """
+        Evaluate `guess_counts()` on a single word with count-only feedback.
+
+        Returns:
+            Number of guesses it took to solve the word
+
+        Raises:
+            ValueError: If the bot fails to solve the word in MAX_GUESSES attempts
+        """
+        ...
+
+    def guess_counts(self, hints: List[CountHint]) -> str:
+        """
+        Make a guess from count-only feedback. Override this to use `evaluate_counts()`.
+
+        Args:
+            hints: Count feedback for each previous guess. Empty list on first guess.
+
+        Returns:
+            A word guess
+
+        Raises:
+            NotImplementedError: If not overridden in subclass
+        """
+        ...
+
+    def remaining_words(
+        self, hints: List[WordleHint], use_corpus: bool = False
+    ) -> List[str]:
+        """
+        Words that could still be the answer given these hints, filtered natively.
+
+        Args:
+            hints: The hint list passed to guess()
+            use_corpus: If True, filter the whole guess corpus instead of the answer list
+
+        Returns:
+            Matching words of the tournament's word length, in list order
+
+        Raises:
+            ValueError: If the hints have different lengths
+
+        Example:
+            >>> def guess(self, hints):
+            ...     return self.remaining_words(hints)[0]
+        """
+        ...
+
+    def remaining_indices(
+        self, hints: List[WordleHint], use_corpus: bool = False
+    ) -> List[int]:
+        """
+        Same as remaining_words(), but as indices into answer_list (or guess_list, with
+        use_corpus).
+        """
+        ...
+
+    def sample_answers(
+        self,
+        n: int = 1000,
+        seed: Optional[int] = None,
+        stratify: str = "none",
+        split: str = "all",
+    ) -> List[str]:
+        """
+        Answers that evaluate(grade_local=True) plays with these options, in file order.
+
+        Args:
+            n: Number of answers (fewer if the split is smaller)
+            seed: Seed for the random sample. Without one the first n answers are taken
+            stratify: 'none', 'first_letter' (each first letter keeps its share of the list)
+                      or 'difficulty' (same for buckets of 0, 1, 2 or 3+ answers one letter
+                      away, like the _IGHT family). Needs a seed
+            split: 'all', 'train' or 'holdout'. Each word's split comes from a hash of the word,
+                   holding out about 20% of answers, the same for every team
+
+        Raises:
+            ValueError: For an unknown stratify or split, or stratify without a seed
+        """
+        ...
+
+    def hard_mode_guesses(self, hints: List[WordleHint]) -> List[str]:
+        """
+        Guess list words that are legal hard-mode guesses after these hints: every revealed
+        green reused in place and every revealed letter reused, in list order.
+        """
+        ...
+
+    def prior_weights(
+        self,
+        table: FrequencyTable,
+        use_corpus: bool = False,
+        smoothing: str = "raw",
+        threshold: Optional[float] = None,
+        width: float = 0.5,
+    ) -> List[float]:
+        """
+        Prior weights from a frequency table, aligned with answer_list (or guess_list, with
+        use_corpus) and summing to 1. See FrequencyTable.priors() for the smoothing options.
+        """
+        ...
+
+    def answers_fitting_share_grid(
+        self, share_text: str, use_corpus: bool = False
+    ) -> List[str]:
+        """
+        Answers (or corpus words, with use_corpus) a pasted share grid could have been played
+        against: for each one, every row must be producible by some valid guess (any word in
+        guess_list or answer_list).
+
+        Raises:
+            ValueError: If the share grid cannot be parsed
+        """
+        ...
+
+    last_game_records: List[GameRecord]
+    """
+    Records of the games played by the most recent evaluate() or evaluate_on_word() call, one
+    per target word. Failed games are recorded too.
+    """
+
+    guess_list: List[str]
+    """
+    Every word in the guess list, in file order. Answers missing from it are valid guesses
+    too; use corpus.is_valid_word() to check a word.
+    """
+
+    answer_list: List[str]
+    """Every word in the answer list, in file order."""
+
+    def check_hint_history(
+        self, hints: List[WordleHint], use_corpus: bool = False
+    ) -> HistoryCheck:
+        """
+        Check whether some word could have produced exactly these hints.
+
+        Hints are applied in order, so when no word fits, the result names the first hint
+        that ruled out every remaining word. Useful for validating hand-written test
+        histories and rejecting corrupted transcripts. Remote evaluation runs the same check
+        on every round of server hints when called with verify_server_hints=True.
+
+        Args:
+            hints: Guess history to check
+            use_corpus: If True, check against the whole guess corpus instead of the
+                        answer list
+
+        Example:
+            >>> check = bot.check_hint_history([WordleHint("crane", "XXXXX"),
+            ...                                 WordleHint("crane", "OOOOO")])
+            >>> check.first_inconsistent_hint
+            1
+        """
+        ...
+
+    def guess(self, hints: GuessHistory) -> str:
         """
         Make a guess based on previous hints.
 
         This is an abstract method that must be overridden by subclasses.
 
         Args:
-            hints: List of previous guesses and their corresponding hints.
-                   Empty list on first guess.
+            hints: Read-only history of previous guesses and their corresponding hints.
+                   Empty on first guess. Bots that opt in receive a GameState instead.
 
         Returns:
             A 5-letter word guess
@@ -194,7 +1229,7 @@ class UChicagoWordleBotBase:
             NotImplementedError: If not overridden in subclass
 
         Example:
-            >>> def guess(self, hints: List[WordleHint]) -> str:
+            >>> def guess(self, hints: GuessHistory) -> str:
             ...     if not hints:
             ...         return "crane"  # First guess
             ...     # Analyze hints and return next guess
@@ -202,3 +1237,86 @@ class UChicagoWordleBotBase:
         """
         ...
 
+
+
+class PatternMatrix:
+    """
+    Precomputed table of hint pattern codes for every corpus word (rows, in corpus order)
+    against every possible answer (columns, in answer list order).
+
+    A pattern code packs a hint into one byte: position i contributes digit * 3**i,
+    where 'X' = 0, '~' = 1 and 'O' = 2. A fully correct guess is 242.
+
+    Example:
+        >>> matrix = PatternMatrix.load_or_build("pattern_matrix.bin")
+        >>> row = matrix.row(matrix.guess_index("crane"))
+        >>> import numpy as np
+        >>> table = np.frombuffer(matrix.as_bytes(), dtype=np.uint8).reshape(
+        ...     matrix.num_guesses, matrix.num_answers)
+    """
+
+    num_guesses: int
+    """Number of rows (corpus words)."""
+
+    num_answers: int
+    """Number of columns (possible answers)."""
+
+    list_hash: int
+    """Hash of both word lists the matrix was built from."""
+
+    guesses: List[str]
+    """Corpus words in row order."""
+
+    answers: List[str]
+    """Possible answers in column order."""
+
+    @staticmethod
+    def build(corpus: Optional[Corpus] = None) -> "PatternMatrix":
+        """
+        Build the matrix from a corpus's 5-letter guesses and answers (the embedded word
+        lists by default; takes a second or so).
+
+        Raises:
+            ValueError: If a 5-letter word is not ASCII
+        """
+        ...
+
+    @staticmethod
+    def load_or_build(path: str, corpus: Optional[Corpus] = None) -> "PatternMatrix":
+        """
+        Load the matrix cached at `path`, or build and save it there if the file is
+        missing, from an older format version, or built from different word lists.
+
+        Raises:
+            OSError: If the file cannot be read or written
+            ValueError: If a 5-letter word is not ASCII
+        """
+        ...
+
+    def save(self, path: str) -> None:
+        """Save the matrix to `path` in the versioned binary cache format."""
+        ...
+
+    def guess_index(self, word: str) -> Optional[int]:
+        """Row index of a corpus word, or None if it is not in the corpus."""
+        ...
+
+    def answer_index(self, word: str) -> Optional[int]:
+        """Column index of an answer, or None if it is not in the answer list."""
+        ...
+
+    def pattern(self, guess_idx: int, answer_idx: int) -> int:
+        """Pattern code for a (guess, answer) pair of indices."""
+        ...
+
+    def hint(self, guess: str, answer: str) -> WordleHint:
+        """The WordleHint `guess` would receive if `answer` were the answer."""
+        ...
+
+    def row(self, guess_idx: int) -> bytes:
+        """Pattern codes of one guess against every answer."""
+        ...
+
+    def as_bytes(self) -> bytes:
+        """The whole matrix as row-major bytes of shape (num_guesses, num_answers)."""
+        ...